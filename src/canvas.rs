@@ -0,0 +1,308 @@
+use std::fmt::{Display, Formatter, Write};
+#[cfg(feature = "ansi")]
+use ansi_term::{Colour, Style};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use crate::FancyTable;
+use crate::style::profile::RenderProfile;
+
+/// A single character cell of a [Canvas]
+#[derive(Debug, PartialEq, Clone)]
+pub struct CanvasCell {
+    /// A grapheme cluster, or an empty string if the cell is covered by a wide grapheme to its left
+    pub symbol: String,
+    #[cfg(feature = "ansi")]
+    pub style: Style,
+}
+
+impl Default for CanvasCell {
+    fn default() -> Self {
+        CanvasCell {
+            symbol: " ".to_string(),
+            #[cfg(feature = "ansi")]
+            style: Style::default(),
+        }
+    }
+}
+
+impl Eq for CanvasCell {}
+
+/// A 2D grid of characters and styles.
+/// Tables can be drawn into a canvas using [FancyTable::to_canvas] and composed with other drawings using [Canvas::blit]
+///
+/// # Example
+/// ```
+/// use fancytable::{Canvas, FancyTable};
+/// use fancytable::style::profile::RenderProfile;
+/// let mut table = FancyTable::new(vec![vec!["Hi".into()]]);
+/// table.set_render_profile(Some(RenderProfile::PLAIN));
+///
+/// let mut screen = Canvas::new(10, 4);
+/// screen.write_str(0, 0, "Status:");
+/// screen.blit(&table.to_canvas(), 2, 1);
+/// assert_eq!(screen.to_string(), "Status:   \n  +----+  \n  | Hi |  \n  +----+  ");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    /// Access: `cells[y * width + x]`
+    cells: Vec<CanvasCell>,
+}
+
+impl Canvas {
+    /// Creates a canvas of the given size filled with spaces
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            cells: vec![CanvasCell::default(); width * height],
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at (x, y) or [None] if it is outside the canvas
+    pub fn get(&self, x: usize, y: usize) -> Option<&CanvasCell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get(y * self.width + x)
+    }
+
+    /// Returns a mutable reference to the cell at (x, y) or [None] if it is outside the canvas
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut CanvasCell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get_mut(y * self.width + x)
+    }
+
+    /// Writes a line of text starting at (x, y) using the default style.
+    /// ANSI escape sequences inside the text are applied to the written cells, text outside the canvas is cut off
+    pub fn write_str(&mut self, x: usize, y: usize, text: &str) {
+        for (offset, cell) in line_cells(text).into_iter().enumerate() {
+            if let Some(target) = self.get_mut(x + offset, y) {
+                *target = cell;
+            }
+        }
+    }
+
+    /// Copies another canvas into this one with its top left corner at (x, y).
+    /// Parts outside this canvas are cut off
+    pub fn blit(&mut self, other: &Canvas, x: usize, y: usize) {
+        for other_y in 0..other.height {
+            for other_x in 0..other.width {
+                if let Some(cell) = self.get_mut(x + other_x, y + other_y) {
+                    *cell = other.get(other_x, other_y).unwrap().clone();
+                }
+            }
+        }
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.cells.chunks(self.width.max(1)).take(self.height).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            #[cfg(feature = "ansi")]
+            for run in row.chunk_by(|a, b| a.style == b.style) {
+                let text: String = run.iter().map(|cell| cell.symbol.as_str()).collect();
+                write!(f, "{}", run[0].style.paint(text))?;
+            }
+
+            #[cfg(not(feature = "ansi"))]
+            write!(f, "{}", row.iter().map(|cell| cell.symbol.as_str()).collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits a line of text into the cells it covers, applying the ANSI escape sequences inside it
+fn line_cells(text: &str) -> Vec<CanvasCell> {
+    #[cfg(feature = "ansi")]
+    let mut style = Style::default();
+
+    let mut cells = vec![];
+    for segment in split_escapes(text) {
+        let text = match segment {
+            Segment::Text(text) => text,
+            #[cfg(feature = "ansi")]
+            Segment::Sgr(params) => {
+                apply_sgr(&mut style, params);
+                continue;
+            }
+            #[cfg(not(feature = "ansi"))]
+            Segment::Sgr(_) => continue,
+        };
+
+        for grapheme in text.graphemes(true) {
+            // wide graphemes cover the following cells
+            for offset in 0..grapheme.width() {
+                cells.push(CanvasCell {
+                    symbol: if offset == 0 { grapheme.to_string() } else { String::new() },
+                    #[cfg(feature = "ansi")]
+                    style,
+                });
+            }
+        }
+    }
+    cells
+}
+
+/// Collects the lines written by the renderer as rows of canvas cells
+#[derive(Default)]
+struct CanvasWriter {
+    rows: Vec<Vec<CanvasCell>>,
+    /// The unfinished line, kept until its line break since escape sequences may be written in several parts
+    line: String,
+}
+
+impl Write for CanvasWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let mut parts = s.split('\n');
+        self.line.push_str(parts.next().unwrap_or_default());
+        for part in parts {
+            self.rows.push(line_cells(&self.line));
+            self.line = part.to_string();
+        }
+        Ok(())
+    }
+}
+
+impl CanvasWriter {
+    /// Returns a canvas as wide as the widest line
+    fn finish(mut self) -> Canvas {
+        if !self.line.is_empty() {
+            self.rows.push(line_cells(&self.line));
+        }
+
+        let mut canvas = Canvas::new(self.rows.iter().map(Vec::len).max().unwrap_or(0), self.rows.len());
+        for (y, row) in self.rows.into_iter().enumerate() {
+            for (x, cell) in row.into_iter().enumerate() {
+                *canvas.get_mut(x, y).unwrap() = cell;
+            }
+        }
+        canvas
+    }
+}
+
+/// A part of a line, either text or the parameters of an SGR escape sequence (`ESC [ ... m`)
+#[cfg_attr(not(feature = "ansi"), allow(dead_code))]
+enum Segment<'a> {
+    Text(&'a str),
+    Sgr(&'a str),
+}
+
+/// Splits a line into text and SGR escape sequences. All other escape sequences are dropped
+fn split_escapes(line: &str) -> Vec<Segment<'_>> {
+    let mut segments = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find('\x1b') {
+        segments.push(Segment::Text(&rest[..start]));
+        let escape = &rest[start + 1..];
+
+        let Some(params) = escape.strip_prefix('[') else {
+            // skips the character following the escape
+            rest = escape.char_indices().nth(1).map_or("", |(idx, _)| &escape[idx..]);
+            continue;
+        };
+
+        // the sequence ends with a byte in the range @ to ~
+        let end = params.find(|c: char| ('@'..='~').contains(&c)).unwrap_or(params.len());
+        if params[end..].starts_with('m') {
+            segments.push(Segment::Sgr(&params[..end]));
+        }
+        rest = params.get(end + 1..).unwrap_or("");
+    }
+    segments.push(Segment::Text(rest));
+
+    segments
+}
+
+/// Applies the parameters of an SGR escape sequence to a style
+#[cfg(feature = "ansi")]
+fn apply_sgr(style: &mut Style, params: &str) {
+    let basic = |idx: u16| [
+        Colour::Black, Colour::Red, Colour::Green, Colour::Yellow,
+        Colour::Blue, Colour::Purple, Colour::Cyan, Colour::White,
+    ][idx as usize];
+
+    let mut codes = params.split(';').map(|code| code.parse::<u16>().unwrap_or(0));
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = Style::default(),
+            1 => style.is_bold = true,
+            2 => style.is_dimmed = true,
+            3 => style.is_italic = true,
+            4 => style.is_underline = true,
+            5 => style.is_blink = true,
+            7 => style.is_reverse = true,
+            8 => style.is_hidden = true,
+            9 => style.is_strikethrough = true,
+            30..=37 => style.foreground = Some(basic(code - 30)),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(basic(code - 40)),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(Colour::Fixed((code - 90 + 8) as u8)),
+            100..=107 => style.background = Some(Colour::Fixed((code - 100 + 8) as u8)),
+            38 | 48 => {
+                let colour = match codes.next() {
+                    Some(5) => codes.next().map(|n| Colour::Fixed(n as u8)),
+                    Some(2) => match (codes.next(), codes.next(), codes.next()) {
+                        (Some(r), Some(g), Some(b)) => Some(Colour::RGB(r as u8, g as u8, b as u8)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if code == 38 {
+                    style.foreground = colour;
+                } else {
+                    style.background = colour;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl FancyTable {
+    /// Draws the table into a new canvas that is exactly as large as the rendered table.
+    /// The symbols of the render profile are used, but the cells keep their styles even if the profile is not colored
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Hi".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// # #[cfg(feature = "ansi")] {
+    /// table.get_mut(0, 0).unwrap().style = ansi_term::Style::new().bold();
+    /// # }
+    ///
+    /// let canvas = table.to_canvas();
+    /// assert_eq!((canvas.get_width(), canvas.get_height()), (6, 3));
+    /// assert_eq!(canvas.get(0, 0).unwrap().symbol, "+");
+    /// # #[cfg(feature = "ansi")] {
+    /// assert!(canvas.get(2, 1).unwrap().style.is_bold);
+    /// # }
+    /// ```
+    pub fn to_canvas(&self) -> Canvas {
+        let profile = RenderProfile { colored: true, ..self.get_render_profile() };
+        let mut writer = CanvasWriter::default();
+        // writing to a canvas never fails
+        let _ = self.write_table(&mut writer, None, &profile);
+        writer.finish()
+    }
+}
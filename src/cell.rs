@@ -1,7 +1,10 @@
-use std::fmt::Alignment;
+use std::borrow::Cow;
+use std::fmt::{Alignment, Debug, Formatter};
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+#[cfg(feature = "ansi")]
 use ansi_term::Style;
-use unicode_width::UnicodeWidthStr;
+use crate::layout::{display_width, truncate_line, wrap_line};
 use crate::style::border::{CellBorderStyle};
 use crate::style::{ColumnWidth, VerticalAlignment};
 
@@ -10,15 +13,74 @@ fn multiline_from_string(s: String) -> Vec<String> {
     s.lines().map(String::from).collect()
 }
 
+/// Produces the content of a lazy cell, see [FancyCell::lazy]
+#[derive(Clone)]
+struct LazyContent(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl Debug for LazyContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LazyContent(..)")
+    }
+}
+
+/// Two lazy contents are only equal if they share the same closure
+impl PartialEq for LazyContent {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// The display width of every line of the content, computed on first use.
+/// Does not take part in comparisons, since it only depends on the content
+#[derive(Debug, Clone, Default)]
+struct WidthCache(OnceLock<Vec<usize>>);
+
+impl PartialEq for WidthCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 /// A single, stylizable cell used inside [FancyTable](crate::FancyTable)
 #[derive(Debug, PartialEq, Clone)]
 pub struct FancyCell {
     content: Vec<String>,
+    /// Replaces the content if set
+    lazy: Option<LazyContent>,
+    /// Must be reset whenever the content changes
+    line_widths: WidthCache,
     pub border_style: CellBorderStyle,
     pub padding: usize,
     pub horizontal_alignment: Alignment,
     pub vertical_alignment: VerticalAlignment,
+    /// Removes blank lines at the start and the end of the content when rendering
+    pub trim_blank_lines: bool,
+    /// The number of blank lines inserted between the lines of the content when rendering
+    pub line_spacing: usize,
+    /// The narrowest content width of the cell, excluding the padding, in a column with [ColumnWidth::Dynamic].
+    /// Reserves space for short or empty cells, so that the column does not change its width with the content
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::{FancyCell, FancyTable};
+    /// let mut status = FancyCell::new("ok".to_string());
+    /// status.min_width = 7;
+    ///
+    /// let mut table = FancyTable::create(vec![vec![status]]);
+    /// assert_eq!(table.get_column_widths(), vec![9]);
+    ///
+    /// table.get_mut(0, 0).unwrap().set_content("failed".to_string());
+    /// assert_eq!(table.get_column_widths(), vec![9]);
+    ///
+    /// table.get_mut(0, 0).unwrap().get_mut_content().clear();
+    /// assert_eq!(table.get_column_widths(), vec![9]);
+    /// ```
+    pub min_width: usize,
+    #[cfg(feature = "ansi")]
     pub style: Style,
+    /// Metadata ignored when rendering to a terminal,
+    /// but added as a tooltip or footnote by the exporters, e.g. [FancyTable::to_html](crate::FancyTable::to_html)
+    pub comment: Option<String>,
 }
 
 impl FancyCell {
@@ -46,18 +108,125 @@ impl FancyCell {
         }
     }
 
+    /// Creates a cell whose content is produced by a closure every time the table is rendered,
+    /// e.g. for values depending on the current time.
+    /// The closure is called once per render and may return multiple lines
+    ///
+    /// The content of a lazy cell is not stored, so [FancyCell::get_content] returns an empty [Vec].
+    /// Use [FancyCell::resolve] to compute it manually
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use fancytable::{FancyCell, FancyTable};
+    /// static RENDERS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let cell = FancyCell::lazy(|| format!("rendered {} times", RENDERS.fetch_add(1, Ordering::Relaxed) + 1));
+    /// let table = FancyTable::create(vec![vec![cell]]);
+    /// assert_eq!(RENDERS.load(Ordering::Relaxed), 0);
+    ///
+    /// assert!(table.to_string().contains("rendered 1 times"));
+    /// assert!(table.to_string().contains("rendered 2 times"));
+    /// ```
+    pub fn lazy(content: impl Fn() -> String + Send + Sync + 'static) -> FancyCell {
+        FancyCell {
+            content: vec![],
+            lazy: Some(LazyContent(Arc::new(content))),
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the content of this cell is computed when rendering, see [FancyCell::lazy]
+    pub fn is_lazy(&self) -> bool {
+        self.lazy.is_some()
+    }
+
+    /// Returns a copy of this cell with the content computed and stored as it is rendered.
+    /// [FancyCell::trim_blank_lines] and [FancyCell::line_spacing] are already applied to the stored content and therefore reset
+    pub fn resolve(&self) -> FancyCell {
+        FancyCell {
+            content: self.lines().into_owned(),
+            lazy: None,
+            line_widths: WidthCache::default(),
+            trim_blank_lines: false,
+            line_spacing: 0,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this cell with the same style, but the content of an empty cell and no comment
+    pub fn clone_style(&self) -> FancyCell {
+        FancyCell {
+            content: FancyCell::default().content,
+            lazy: None,
+            line_widths: WidthCache::default(),
+            comment: None,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this cell with the same content and comment, but the default style
+    pub fn clone_content(&self) -> FancyCell {
+        FancyCell {
+            content: self.content.clone(),
+            lazy: self.lazy.clone(),
+            comment: self.comment.clone(),
+            ..FancyCell::default()
+        }
+    }
+
+    /// Returns the content as it is rendered, computing it for lazy cells
+    /// and applying [FancyCell::trim_blank_lines] and [FancyCell::line_spacing]
+    fn lines(&self) -> Cow<'_, [String]> {
+        let lines = match &self.lazy {
+            Some(lazy) => Cow::Owned(multiline_from_string((lazy.0)())),
+            None => Cow::Borrowed(self.content.as_slice()),
+        };
+        if !self.trim_blank_lines && self.line_spacing == 0 {
+            return lines;
+        }
+
+        let mut lines = lines.as_ref();
+        if self.trim_blank_lines {
+            let blank = |line: &String| line.trim().is_empty();
+            let start = lines.iter().position(|line| !blank(line)).unwrap_or(lines.len());
+            let end = lines.iter().rposition(|line| !blank(line)).map_or(start, |end| end + 1);
+            lines = &lines[start..end];
+        }
+
+        let mut arranged = vec![];
+        for (idx, line) in lines.iter().enumerate() {
+            if idx > 0 {
+                arranged.resize(arranged.len() + self.line_spacing, String::new());
+            }
+            arranged.push(line.clone());
+        }
+
+        // a cell always has at least one line
+        if arranged.is_empty() {
+            arranged.push(String::new());
+        }
+        Cow::Owned(arranged)
+    }
+
     /// Returns the multi line content of the cell.
     pub fn get_content(&self) -> &Vec<String> {
         &self.content
     }
 
-    /// Returns the multi line content as a mutable [Vec]
+    /// Returns the multi line content as a mutable [Vec].
+    /// A lazy cell becomes a regular cell with empty content
     pub fn get_mut_content(&mut self) -> &mut Vec<String> {
+        self.lazy = None;
+        self.line_widths = WidthCache::default();
         &mut self.content
     }
 
     /// Sets the content of the cell using a multiline string.
+    /// A lazy cell becomes a regular cell
     pub fn set_content(&mut self, content: String) {
+        self.lazy = None;
+        self.line_widths = WidthCache::default();
         self.content = multiline_from_string(content);
     }
 
@@ -65,34 +234,46 @@ impl FancyCell {
     ///
     /// Returns [None] if the line does not exist.
     pub fn get_mut_line(&mut self, line: usize) -> Option<&mut String> {
+        self.line_widths = WidthCache::default();
         self.content.get_mut(line)
     }
 
     /// Sets a single line inside the cell.
     pub fn set_line(&mut self, line: usize, content: String) {
+        self.line_widths = WidthCache::default();
         self.content[line] = content;
     }
 
     /// Returns the height of the cell in lines.
     pub fn get_height(&self, dynamic_width: ColumnWidth) -> usize {
         match dynamic_width {
-            ColumnWidth::Dynamic => self.content.len(),
+            ColumnWidth::Dynamic | ColumnWidth::Truncated(_) => self.lines().len(),
             ColumnWidth::Fixed(w) => self.get_lines_with_fixed_width(w).len()
         }
     }
 
-    /// Returns the unicode column width of this cell.
+    /// Returns the unicode column width of this cell including the padding, ignoring ANSI escape sequences.
     /// See [UnicodeWidthStr::width] for more information.
+    ///
+    /// The widths of the lines are cached until the content is changed,
+    /// except for lazy cells and cells with [FancyCell::trim_blank_lines], whose rendered lines differ from the content.
+    /// In a column with [ColumnWidth::Dynamic], the content is at least [FancyCell::min_width] wide.
     pub fn get_width(&self, dynamic_width: ColumnWidth) -> usize {
-        if let ColumnWidth::Fixed(w) = dynamic_width {
+        if let ColumnWidth::Fixed(w) | ColumnWidth::Truncated(w) = dynamic_width {
             return w + 2;
         }
 
-        (0..self.content.len())
-            .map(|i| strip_ansi_escapes::strip_str(self.get_line(i, dynamic_width).unwrap()))
-            .map(|s| s.width())
-            .max()
-            .unwrap_or(0)
+        // blank lines only add empty lines, which never widen the cell
+        let widest = match &self.lazy {
+            None if !self.trim_blank_lines => self.get_line_widths().iter().copied().max(),
+            _ => self.lines().iter().map(|line| display_width(line)).max(),
+        };
+        widest.unwrap_or(0).max(self.min_width) + 2 * self.padding
+    }
+
+    /// Returns the cached display width of every line of the content
+    fn get_line_widths(&self) -> &Vec<usize> {
+        self.line_widths.0.get_or_init(|| self.content.iter().map(|line| display_width(line)).collect())
     }
 
     /// Returns a single padded line inside this cell.
@@ -100,26 +281,63 @@ impl FancyCell {
     /// Returns [None] if the line does not exist.
     pub fn get_line(&self, line: usize, width: ColumnWidth) -> Option<String> {
         let line = match width {
-            ColumnWidth::Dynamic => self.content.get(line)?.clone(),
+            ColumnWidth::Dynamic => self.lines().get(line)?.clone(),
             ColumnWidth::Fixed(w) => self.get_lines_with_fixed_width(w).get(line)?.clone(),
+            ColumnWidth::Truncated(w) => truncate_line(self.lines().get(line)?, w),
         };
 
-        let empty = "";
-        let padding = match width {
+        Some(self.pad_line(&line, width))
+    }
+
+    /// Returns all padded lines inside this cell.
+    /// This is cheaper than calling [FancyCell::get_line] for every line, since the content is wrapped only once.
+    pub fn get_lines(&self, width: ColumnWidth) -> Vec<String> {
+        self.get_wrapped_lines(width).iter()
+            .map(|line| self.pad_line(line, width))
+            .collect()
+    }
+
+    /// Returns all lines inside this cell without padding.
+    /// If the column has a fixed width, the lines are wrapped or truncated to fit it.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyCell;
+    /// use fancytable::style::ColumnWidth;
+    /// let mut cell = FancyCell::new("\npasted\ntext\n            ".to_string());
+    /// cell.trim_blank_lines = true;
+    /// cell.line_spacing = 1;
+    /// assert_eq!(cell.get_wrapped_lines(ColumnWidth::Dynamic), vec!["pasted", "", "text"]);
+    /// assert_eq!(cell.get_width(ColumnWidth::Dynamic), 8);
+    /// ```
+    pub fn get_wrapped_lines(&self, width: ColumnWidth) -> Vec<String> {
+        match width {
+            ColumnWidth::Dynamic => self.lines().into_owned(),
+            ColumnWidth::Fixed(w) => self.get_lines_with_fixed_width(w),
+            ColumnWidth::Truncated(w) => self.lines().iter().map(|line| truncate_line(line, w)).collect(),
+        }
+    }
+
+    /// Returns the padding on each side of the cell.
+    /// Cells inside a column with a fixed width always have a padding of 1
+    pub fn get_padding(&self, width: ColumnWidth) -> usize {
+        match width {
             ColumnWidth::Dynamic => self.padding,
-            ColumnWidth::Fixed(_) => 1,
-        };
-        let padded = format!("{empty:width$}{line}{empty:width$}", width = padding);
-        Some(padded)
+            ColumnWidth::Fixed(_) | ColumnWidth::Truncated(_) => 1,
+        }
+    }
+
+    /// Adds the padding to both sides of a line
+    fn pad_line(&self, line: &str, width: ColumnWidth) -> String {
+        let empty = "";
+        format!("{empty:width$}{line}{empty:width$}", width = self.get_padding(width))
     }
 
     pub fn get_lines_with_fixed_width(&self, width: usize) -> Vec<String> {
         let mut content: Vec<String> = Vec::new();
 
-        for line in &self.content {
-            let wrapped = textwrap::wrap(line.as_str(), width);
-            let mut wrapped: Vec<String> = wrapped.iter().map(|l| l.to_string()).collect();
-            content.append(&mut wrapped);
+        for line in self.lines().iter() {
+            content.append(&mut wrap_line(line, width));
         }
 
         content
@@ -130,11 +348,18 @@ impl Default for FancyCell {
     fn default() -> Self {
         FancyCell {
             content: vec![" ".to_string()],
+            lazy: None,
+            line_widths: WidthCache::default(),
             border_style: Default::default(),
             padding: 1,
             horizontal_alignment: Alignment::Left,
             vertical_alignment: VerticalAlignment::default(),
+            trim_blank_lines: false,
+            line_spacing: 0,
+            min_width: 0,
+            #[cfg(feature = "ansi")]
             style: Style::default(),
+            comment: None,
         }
     }
 }
@@ -0,0 +1,46 @@
+/// Selects how escape sequences and control characters inside the content of cells are rendered,
+/// see [FancyTable::set_escape_mode](crate::FancyTable::set_escape_mode)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum EscapeMode {
+    /// The content is written as it is, so cells may contain colors
+    #[default]
+    Raw,
+    /// Escape sequences and control characters are removed, tabs are replaced by a space
+    Strip,
+    /// Control characters are replaced by their Unicode control pictures, e.g. `ESC` by `␛`,
+    /// so that escape sequences are shown instead of being interpreted by the terminal
+    Visualize,
+}
+
+impl EscapeMode {
+    /// Neutralizes a single line of content
+    pub(crate) fn apply(&self, line: &str) -> String {
+        match self {
+            EscapeMode::Raw => line.to_string(),
+            EscapeMode::Strip => strip_ansi_escapes::strip_str(line).chars()
+                .filter_map(|c| match c {
+                    '\t' => Some(' '),
+                    c if is_unsafe(c) => None,
+                    c => Some(c),
+                })
+                .collect(),
+            EscapeMode::Visualize => line.chars().map(visualize).collect(),
+        }
+    }
+}
+
+/// Returns whether a character moves the cursor, starts an escape sequence or changes the direction of the text
+fn is_unsafe(c: char) -> bool {
+    c.is_control() || matches!(c, '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Replaces an unsafe character by a visible symbol
+fn visualize(c: char) -> char {
+    match c {
+        // the control pictures block mirrors the C0 control characters
+        '\0'..='\x1f' => char::from_u32(0x2400 + c as u32).unwrap(),
+        '\x7f' => '␡',
+        c if is_unsafe(c) => '\u{FFFD}',
+        c => c,
+    }
+}
@@ -0,0 +1,243 @@
+use std::fmt::{Alignment, Write};
+use crate::{EscapeMode, FancyCell, FancyTable};
+use crate::layout::display_width;
+use crate::style::ColumnWidth;
+
+/// Returns the lines of a cell without padding and ANSI escape sequences, with the escape mode of the table applied
+fn plain_lines(cell: &FancyCell, escape_mode: EscapeMode) -> Vec<String> {
+    cell.get_wrapped_lines(ColumnWidth::Dynamic).iter()
+        .map(|line| strip_ansi_escapes::strip_str(escape_mode.apply(line)).trim().to_string())
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn escape_latex(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\\' => "\\textbackslash{}".to_string(),
+            '~' => "\\textasciitilde{}".to_string(),
+            '^' => "\\textasciicircum{}".to_string(),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => format!("\\{c}"),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+impl FancyTable {
+    /// Returns every row below the header as a block of `Header: value` lines, separated by blank lines.
+    /// Without box drawing, the output works well with screen readers and on very narrow terminals.
+    ///
+    /// The labels are taken from the header rows, or numbered if there are none.
+    /// See [FancyTable::set_plain_list_threshold] to select this output automatically
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["Name".into(), "Score".into()],
+    ///     vec!["Alice".into(), "42".into()],
+    ///     vec!["Bob".into(), "7".into()],
+    /// ]);
+    /// table.set_header_rows(1);
+    /// assert_eq!(table.to_plain_list(), "Name: Alice\nScore: 42\n\nName: Bob\nScore: 7");
+    /// ```
+    pub fn to_plain_list(&self) -> String {
+        let header_rows = self.get_header_rows().min(self.get_row_count());
+        let labels: Vec<String> = (0..self.get_column_count())
+            .map(|col| match header_rows {
+                0 => format!("Column {}", col + 1),
+                _ => (0..header_rows)
+                    .flat_map(|row| plain_lines(self.get(row, col).unwrap(), self.get_escape_mode()))
+                    .filter(|line| !line.is_empty())
+                    .collect::<Vec<String>>()
+                    .join(" "),
+            })
+            .collect();
+
+        let blocks: Vec<String> = (header_rows..self.get_row_count())
+            .map(|row| {
+                let mut block = String::new();
+                for (col, label) in labels.iter().enumerate() {
+                    let lines = plain_lines(self.get(row, col).unwrap(), self.get_escape_mode());
+                    let _ = write!(block, "{label}:");
+                    for (idx, line) in lines.iter().enumerate().filter(|(_, line)| !line.is_empty()) {
+                        // continuation lines are aligned with the first line of the value
+                        let indent = if idx == 0 { 1 } else { display_width(label) + 2 };
+                        if idx > 0 {
+                            block.push('\n');
+                        }
+                        let _ = write!(block, "{:indent$}{line}", "");
+                    }
+                    block.push('\n');
+                }
+                block.trim_end().to_string()
+            })
+            .collect();
+
+        blocks.join("\n\n")
+    }
+
+    /// Returns the table as an HTML `<table>`.
+    /// Header rows are placed inside `<thead>`, the comments of cells become `title` attributes.
+    /// Styles and borders are not exported
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["Revenue".into()], vec!["42".into()]]);
+    /// table.set_header_rows(1);
+    /// table.get_mut(1, 0).unwrap().comment = Some("estimated".into());
+    /// assert!(table.to_html().contains("<td title=\"estimated\">42</td>"));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<table>\n");
+        let header_rows = self.get_header_rows().min(self.get_row_count());
+
+        for row in 0..self.get_row_count() {
+            if row == 0 && header_rows > 0 {
+                html.push_str("<thead>\n");
+            }
+            if row == header_rows {
+                html.push_str("<tbody>\n");
+            }
+
+            let tag = if row < header_rows { "th" } else { "td" };
+            html.push_str("<tr>");
+            for col in 0..self.get_column_count() {
+                let cell = self.get(row, col).unwrap();
+                let content: Vec<String> = plain_lines(cell, self.get_escape_mode()).iter().map(|line| escape_html(line)).collect();
+
+                let _ = write!(html, "<{tag}");
+                if let Some(comment) = &cell.comment {
+                    let _ = write!(html, " title=\"{}\"", escape_html(comment));
+                }
+                match cell.horizontal_alignment {
+                    Alignment::Left => {}
+                    Alignment::Center => html.push_str(" style=\"text-align: center\""),
+                    Alignment::Right => html.push_str(" style=\"text-align: right\""),
+                }
+                let _ = write!(html, ">{}</{tag}>", content.join("<br>"));
+            }
+            html.push_str("</tr>\n");
+
+            if row + 1 == header_rows {
+                html.push_str("</thead>\n");
+            }
+        }
+
+        if header_rows < self.get_row_count() {
+            html.push_str("</tbody>\n");
+        }
+        html.push_str("</table>");
+        html
+    }
+
+    /// Returns the table as a GitHub flavored Markdown table.
+    /// The first row is used as the header, the comments of cells become footnotes below the table.
+    /// Styles and borders are not exported
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["Revenue".into()], vec!["42".into()]]);
+    /// table.get_mut(1, 0).unwrap().comment = Some("estimated".into());
+    /// assert_eq!(table.to_markdown(), "| Revenue |\n| --- |\n| 42[^1] |\n\n[^1]: estimated");
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        let mut footnotes = vec![];
+
+        for row in 0..self.get_row_count() {
+            markdown.push('|');
+            for col in 0..self.get_column_count() {
+                let cell = self.get(row, col).unwrap();
+                let content: Vec<String> = plain_lines(cell, self.get_escape_mode()).iter().map(|line| escape_markdown(line)).collect();
+                let _ = write!(markdown, " {}", content.join("<br>"));
+
+                if let Some(comment) = &cell.comment {
+                    footnotes.push(comment.lines().collect::<Vec<&str>>().join(" "));
+                    let _ = write!(markdown, "[^{}]", footnotes.len());
+                }
+                markdown.push_str(" |");
+            }
+            markdown.push('\n');
+
+            if row == 0 {
+                markdown.push('|');
+                for col in 0..self.get_column_count() {
+                    let alignment = self.get(1, col).unwrap_or(self.get(0, col).unwrap()).horizontal_alignment;
+                    markdown.push_str(match alignment {
+                        Alignment::Left => " --- |",
+                        Alignment::Center => " :---: |",
+                        Alignment::Right => " ---: |",
+                    });
+                }
+                markdown.push('\n');
+            }
+        }
+
+        if !footnotes.is_empty() {
+            markdown.push('\n');
+            for (idx, footnote) in footnotes.iter().enumerate() {
+                let _ = writeln!(markdown, "[^{}]: {footnote}", idx + 1);
+            }
+        }
+        markdown.truncate(markdown.trim_end_matches('\n').len());
+        markdown
+    }
+
+    /// Returns the table as a LaTeX `tabular` environment.
+    /// The column alignment is taken from the first row below the header, the comments of cells become footnotes.
+    /// Styles are not exported, but a line is drawn below the header rows
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["Revenue".into()], vec!["42".into()]]);
+    /// table.get_mut(1, 0).unwrap().comment = Some("estimated".into());
+    /// assert!(table.to_latex().contains("42\\footnote{estimated} \\\\"));
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let header_rows = self.get_header_rows().min(self.get_row_count());
+        let alignment_row = header_rows.min(self.get_row_count().saturating_sub(1));
+
+        let columns: String = (0..self.get_column_count())
+            .map(|col| match self.get(alignment_row, col).map(|cell| cell.horizontal_alignment) {
+                Some(Alignment::Center) => 'c',
+                Some(Alignment::Right) => 'r',
+                _ => 'l',
+            })
+            .collect();
+
+        let mut latex = format!("\\begin{{tabular}}{{{columns}}}\n\\hline\n");
+        for row in 0..self.get_row_count() {
+            let cells: Vec<String> = (0..self.get_column_count())
+                .map(|col| {
+                    let cell = self.get(row, col).unwrap();
+                    let mut content = escape_latex(&plain_lines(cell, self.get_escape_mode()).join(" "));
+                    if let Some(comment) = &cell.comment {
+                        let _ = write!(content, "\\footnote{{{}}}", escape_latex(comment));
+                    }
+                    content
+                })
+                .collect();
+
+            let _ = writeln!(latex, "{} \\\\", cells.join(" & "));
+            if row + 1 == header_rows {
+                latex.push_str("\\hline\n");
+            }
+        }
+        latex.push_str("\\hline\n\\end{tabular}");
+        latex
+    }
+}
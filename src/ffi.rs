@@ -0,0 +1,208 @@
+//! A minimal C ABI over [FancyTable], enabled by the `ffi` feature.
+//!
+//! Tables are passed around as opaque handles created by [fancytable_new] and destroyed by [fancytable_free].
+//! Functions returning a `c_int` return `0` on success and `-1` if an argument is invalid.
+//!
+//! Enums are passed as integers:
+//! - [BorderLineStyle]: `0` solid, `1` dashed, `2` dotted, `3` none
+//! - [BorderStyle]: `0` single, `1` double
+//! - [Alignment]: `0` left, `1` center, `2` right
+//! - [VerticalAlignment]: `0` top, `1` center, `2` bottom
+//! - [RenderProfile]: `0` detect, `1` fancy, `2` plain
+//!
+//! To build a shared library, run `cargo rustc --release --features ffi --crate-type cdylib`
+
+use std::ffi::{c_char, c_int, CStr};
+use std::fmt::Alignment;
+use crate::{FancyCell, FancyTable};
+use crate::style::border::{BorderLineStyle, BorderStyle, CellBorderStyle};
+use crate::style::profile::RenderProfile;
+use crate::style::VerticalAlignment;
+
+fn border_line_style(value: u8) -> Option<BorderLineStyle> {
+    match value {
+        0 => Some(BorderLineStyle::Solid),
+        1 => Some(BorderLineStyle::Dashed),
+        2 => Some(BorderLineStyle::Dotted),
+        3 => Some(BorderLineStyle::None),
+        _ => None,
+    }
+}
+
+fn border_style(value: u8) -> Option<BorderStyle> {
+    match value {
+        0 => Some(BorderStyle::Single),
+        1 => Some(BorderStyle::Double),
+        _ => None,
+    }
+}
+
+fn alignment(value: u8) -> Option<Alignment> {
+    match value {
+        0 => Some(Alignment::Left),
+        1 => Some(Alignment::Center),
+        2 => Some(Alignment::Right),
+        _ => None,
+    }
+}
+
+fn vertical_alignment(value: u8) -> Option<VerticalAlignment> {
+    match value {
+        0 => Some(VerticalAlignment::Top),
+        1 => Some(VerticalAlignment::Center),
+        2 => Some(VerticalAlignment::Bottom),
+        _ => None,
+    }
+}
+
+fn result(ok: bool) -> c_int {
+    if ok { 0 } else { -1 }
+}
+
+/// Creates a new table with the given amount of empty rows and columns.
+/// The returned handle must be freed using [fancytable_free]
+#[no_mangle]
+pub extern "C" fn fancytable_new(rows: usize, columns: usize) -> *mut FancyTable {
+    let table = FancyTable::create(vec![vec![FancyCell::default(); columns]; rows]);
+    Box::into_raw(Box::new(table))
+}
+
+/// Frees a table created by [fancytable_new]. Passing a null pointer does nothing.
+///
+/// # Safety
+/// `table` must be null or a handle returned by [fancytable_new] that has not been freed yet
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_free(table: *mut FancyTable) {
+    if !table.is_null() {
+        drop(Box::from_raw(table));
+    }
+}
+
+/// Sets the content of a cell from a NUL-terminated UTF-8 string.
+/// Rows and columns are created if needed.
+///
+/// # Safety
+/// `table` must be a valid handle and `content` must be null or a valid NUL-terminated string
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_cell(table: *mut FancyTable, row: usize, column: usize, content: *const c_char) -> c_int {
+    let (Some(table), false) = (table.as_mut(), content.is_null()) else {
+        return -1;
+    };
+    let Ok(content) = CStr::from_ptr(content).to_str() else {
+        return -1;
+    };
+
+    table.set(row, column, content.into());
+    0
+}
+
+/// Sets the alignment of a cell
+///
+/// # Safety
+/// `table` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_cell_alignment(table: *mut FancyTable, row: usize, column: usize, horizontal: u8, vertical: u8) -> c_int {
+    let Some(cell) = table.as_mut().and_then(|table| table.get_mut(row, column)) else {
+        return -1;
+    };
+    let (Some(horizontal), Some(vertical)) = (alignment(horizontal), vertical_alignment(vertical)) else {
+        return -1;
+    };
+
+    cell.horizontal_alignment = horizontal;
+    cell.vertical_alignment = vertical;
+    0
+}
+
+/// Sets the line styles of the borders of a cell
+///
+/// # Safety
+/// `table` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_cell_border(table: *mut FancyTable, row: usize, column: usize, top: u8, left: u8, right: u8, bottom: u8) -> c_int {
+    let Some(cell) = table.as_mut().and_then(|table| table.get_mut(row, column)) else {
+        return -1;
+    };
+    let (Some(top), Some(left), Some(right), Some(bottom)) =
+        (border_line_style(top), border_line_style(left), border_line_style(right), border_line_style(bottom)) else {
+        return -1;
+    };
+
+    cell.border_style = CellBorderStyle { top, left, right, bottom };
+    0
+}
+
+/// Sets the style of a horizontal separator
+///
+/// # Safety
+/// `table` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_horizontal_separator_style(table: *mut FancyTable, idx: usize, style: u8) -> c_int {
+    let (Some(table), Some(style)) = (table.as_mut(), border_style(style)) else {
+        return -1;
+    };
+    let valid = table.get_horizontal_separator_style(idx).is_some();
+    if valid {
+        table.set_horizontal_separator_style(idx, style);
+    }
+    result(valid)
+}
+
+/// Sets the style of a vertical separator
+///
+/// # Safety
+/// `table` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_vertical_separator_style(table: *mut FancyTable, idx: usize, style: u8) -> c_int {
+    let (Some(table), Some(style)) = (table.as_mut(), border_style(style)) else {
+        return -1;
+    };
+    let valid = table.get_vertical_separator_style(idx).is_some();
+    if valid {
+        table.set_vertical_separator_style(idx, style);
+    }
+    result(valid)
+}
+
+/// Overrides the render profile of the table
+///
+/// # Safety
+/// `table` must be a valid handle
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_set_render_profile(table: *mut FancyTable, profile: u8) -> c_int {
+    let Some(table) = table.as_mut() else {
+        return -1;
+    };
+    let profile = match profile {
+        0 => None,
+        1 => Some(RenderProfile::FANCY),
+        2 => Some(RenderProfile::PLAIN),
+        _ => return -1,
+    };
+
+    table.set_render_profile(profile);
+    0
+}
+
+/// Renders the table into `buffer` as a NUL-terminated UTF-8 string.
+///
+/// Returns the size of the rendered table in bytes, including the NUL terminator, or `0` if `table` is null.
+/// If the returned size is larger than `len`, nothing has been written and the call should be repeated with a larger buffer.
+/// Passing a null `buffer` can be used to query the required size.
+///
+/// # Safety
+/// `table` must be a valid handle and `buffer` must be null or valid for writes of `len` bytes
+#[no_mangle]
+pub unsafe extern "C" fn fancytable_render(table: *const FancyTable, buffer: *mut c_char, len: usize) -> usize {
+    let Some(table) = table.as_ref() else {
+        return 0;
+    };
+
+    let rendered = table.to_string();
+    let size = rendered.len() + 1;
+    if !buffer.is_null() && size <= len {
+        std::ptr::copy_nonoverlapping(rendered.as_ptr(), buffer as *mut u8, rendered.len());
+        *buffer.add(rendered.len()) = 0;
+    }
+    size
+}
@@ -0,0 +1,33 @@
+use std::ops::Range;
+
+/// A label spanning several columns, rendered above the first row of a table.
+/// Used for two-level headers like "Q1 | Q2" above "Jan Feb Mar | Apr May Jun"
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ColumnGroup {
+    pub label: String,
+    pub columns: Range<usize>,
+}
+
+/// Splits the columns of a table into consecutive segments, one per group.
+/// Columns without a group form a segment of their own without a label.
+///
+/// Empty groups, groups outside the table and groups overlapping a previous group are ignored
+pub(crate) fn group_segments(groups: &[ColumnGroup], columns: usize) -> Vec<(Range<usize>, &str)> {
+    let mut sorted: Vec<&ColumnGroup> = groups.iter().collect();
+    sorted.sort_by_key(|group| group.columns.start);
+
+    let mut segments = Vec::new();
+    let mut col = 0;
+    for group in sorted {
+        if group.columns.is_empty() || group.columns.start < col || group.columns.end > columns {
+            continue;
+        }
+
+        segments.extend((col..group.columns.start).map(|c| (c..(c + 1), "")));
+        segments.push((group.columns.clone(), group.label.as_str()));
+        col = group.columns.end;
+    }
+    segments.extend((col..columns).map(|c| (c..(c + 1), "")));
+
+    segments
+}
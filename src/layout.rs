@@ -0,0 +1,421 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Alignment;
+use std::ops::Range;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use crate::{EscapeMode, FancyCell, FancyTable};
+use crate::style::ColumnWidth;
+use crate::group::group_segments;
+use crate::solver::{solve_widths, SpanConstraint};
+
+/// The geometry of a table, computed once before rendering.
+/// Only depends on the content of the cells, never on their styles
+///
+/// The column widths always cover the whole table, while the lines are only computed for a range of rows.
+/// With the `parallel` feature, the lines of the rows and the column widths are computed on the rayon thread pool
+pub(crate) struct Layout {
+    /// The width of every column including the padding of the cells
+    pub column_widths: Vec<usize>,
+    /// The rows the lines have been computed for
+    pub rows: Range<usize>,
+    /// The height of every row in [Layout::rows] in lines
+    row_heights: Vec<usize>,
+    /// The wrapped lines of every stored cell in [Layout::rows], without padding.
+    /// Access: `lines[&(row, col)]`
+    lines: CellLines,
+    /// The wrapped lines of the cell a sparse table does not store, shared by all of its positions.
+    /// Access: `default_lines[col]`
+    default_lines: Vec<Vec<String>>,
+}
+
+impl Layout {
+    /// Computes the layout of the whole table
+    pub fn compute(table: &FancyTable) -> Layout {
+        Layout::compute_rows(table, 0..table.get_row_count())
+    }
+
+    /// Computes the column widths of the whole table, but the lines only for the given rows
+    pub fn compute_rows(table: &FancyTable, rows: Range<usize>) -> Layout {
+        let cells = Cells::resolve(table);
+        let positions = cells.positions(0..table.get_row_count());
+        let decimal_parts = compute_decimal_parts(&cells, &positions);
+        let (lines, default_lines) = compute_lines(&cells, rows.clone(), &decimal_parts);
+        let row_heights = compute_row_heights(table, rows.clone(), &lines, &default_lines);
+
+        let mut column_widths = compute_column_widths(&cells, &positions, &decimal_parts);
+        widen_for_groups(table, &mut column_widths);
+
+        Layout {
+            column_widths,
+            rows,
+            row_heights,
+            lines,
+            default_lines,
+        }
+    }
+
+    /// Returns the height of a row in lines
+    pub fn row_height(&self, row: usize) -> usize {
+        self.row_heights[row - self.rows.start]
+    }
+
+    /// Returns the sum of the heights of all rows in [Layout::rows]
+    pub fn total_row_height(&self) -> usize {
+        self.row_heights.iter().sum()
+    }
+
+    /// Returns the lines of a cell without padding
+    pub fn lines(&self, row: usize, col: usize) -> &[String] {
+        self.lines.get(&(row, col)).unwrap_or(&self.default_lines[col])
+    }
+}
+
+/// Returns the unicode width of a line, ignoring ANSI escape sequences
+pub(crate) fn display_width(line: &str) -> usize {
+    strip_ansi_escapes::strip_str(line).width()
+}
+
+/// Cuts off a line that is wider than `width` and ends it with `…`.
+/// Grapheme clusters, e.g. emoji sequences or letters with combining marks, are never split.
+/// ANSI escape sequences are removed from cut off lines
+pub(crate) fn truncate_line(line: &str, width: usize) -> String {
+    if display_width(line) <= width {
+        return line.to_string();
+    }
+
+    let mut truncated = String::new();
+    let mut truncated_width = 0;
+    for grapheme in strip_ansi_escapes::strip_str(line).graphemes(true) {
+        let grapheme_width = grapheme.width();
+        // leaves space for the ellipsis
+        if truncated_width + grapheme_width >= width {
+            break;
+        }
+        truncated.push_str(grapheme);
+        truncated_width += grapheme_width;
+    }
+
+    if width > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+/// Wraps a line at word boundaries into lines of at most `width` columns.
+/// Words that are too long are split between grapheme clusters
+pub(crate) fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let options = textwrap::Options::new(width).break_words(false);
+    let mut lines = vec![];
+
+    for wrapped in textwrap::wrap(line, options) {
+        if display_width(&wrapped) <= width {
+            lines.push(wrapped.into_owned());
+            continue;
+        }
+
+        let mut current = String::new();
+        let mut current_width = 0;
+        for grapheme in wrapped.graphemes(true) {
+            let grapheme_width = grapheme.width();
+            if current_width + grapheme_width > width && !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            }
+            current.push_str(grapheme);
+            current_width += grapheme_width;
+        }
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Aligns a line within the given width, filling the remaining space with `fill`.
+/// The width is measured using [display_width]
+pub(crate) fn align_line(line: &str, width: usize, alignment: Alignment, fill: char) -> String {
+    let space = width.saturating_sub(display_width(line));
+    let (left, right) = match alignment {
+        Alignment::Left => (0, space),
+        Alignment::Right => (space, 0),
+        Alignment::Center => (space / 2, space - space / 2),
+    };
+
+    let fill = fill.to_string();
+    format!("{}{line}{}", fill.repeat(left), fill.repeat(right))
+}
+
+/// Appends the sort indicator to the first line of a header cell.
+/// In columns with a fixed or truncated width, the line is cut off so that the indicator is neither wrapped nor cut off
+fn append_glyph(line: &str, glyph: &str, width: ColumnWidth) -> String {
+    let line = line.trim_end();
+    match width {
+        ColumnWidth::Dynamic => format!("{line} {glyph}"),
+        ColumnWidth::Fixed(w) | ColumnWidth::Truncated(w) => match w.checked_sub(display_width(glyph) + 1) {
+            Some(room) if room > 0 => format!("{} {glyph}", truncate_line(line, room)),
+            _ => glyph.to_string(),
+        },
+    }
+}
+
+/// The wrapped lines of the stored cells by their position
+type CellLines = HashMap<(usize, usize), Vec<String>>;
+
+/// The cells of a table with the content of every lazy cell computed exactly once,
+/// the escape mode applied and the sort indicator appended to the header of the sorted column
+struct Cells<'a> {
+    table: &'a FancyTable,
+    resolved: HashMap<(usize, usize), FancyCell>,
+    /// The cell of every position a sparse table does not store, prepared like the stored cells
+    default: Option<Cow<'a, FancyCell>>,
+}
+
+impl<'a> Cells<'a> {
+    fn resolve(table: &'a FancyTable) -> Cells<'a> {
+        let escape_mode = table.get_escape_mode();
+        let prepare = |cell: &FancyCell| {
+            if escape_mode != EscapeMode::Raw {
+                let mut cell = cell.resolve();
+                for line in cell.get_mut_content() {
+                    *line = escape_mode.apply(line);
+                }
+                Some(cell)
+            } else if cell.is_lazy() {
+                Some(cell.resolve())
+            } else {
+                None
+            }
+        };
+
+        let mut resolved: HashMap<(usize, usize), FancyCell> = table.get_stored_cells(0..table.get_row_count())
+            .filter_map(|(idx, cell)| Some((idx, prepare(cell)?)))
+            .collect();
+        let default = table.get_default_cell()
+            .map(|cell| prepare(cell).map_or(Cow::Borrowed(cell), Cow::Owned));
+
+        if let Some((row, col, glyph)) = table.get_rendered_sort_indicator() {
+            let mut cell = match resolved.remove(&(row, col)) {
+                Some(cell) => cell,
+                None => table.get(row, col).map(FancyCell::resolve).unwrap_or_default(),
+            };
+            let content = cell.get_mut_content();
+            match content.first_mut() {
+                Some(line) => *line = append_glyph(line, &glyph, table.get_column_width(col)),
+                None => content.push(glyph),
+            }
+            resolved.insert((row, col), cell);
+        }
+
+        Cells { table, resolved, default }
+    }
+
+    /// Returns whether a sparse table does not store the cell at (row, col)
+    fn is_default(&self, row: usize, col: usize) -> bool {
+        match (self.table.get(row, col), self.table.get_default_cell()) {
+            (Some(cell), Some(default)) => std::ptr::eq(cell, default),
+            _ => false,
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> Option<&FancyCell> {
+        if let Some(cell) = self.resolved.get(&(row, col)) {
+            return Some(cell);
+        }
+        match &self.default {
+            Some(default) if self.is_default(row, col) => Some(default),
+            _ => self.table.get(row, col),
+        }
+    }
+
+    /// Returns the positions of the stored cells in the given rows.
+    /// Resolved cells are included even if a sparse table does not store them, e.g. the header with the sort indicator
+    fn positions(&self, rows: Range<usize>) -> Vec<(usize, usize)> {
+        let mut positions: Vec<(usize, usize)> = self.table.get_stored_cells(rows.clone()).map(|(idx, _)| idx).collect();
+        positions.extend(self.resolved.keys().filter(|&&(row, col)| rows.contains(&row) && self.is_default(row, col)));
+        positions
+    }
+
+    /// Returns whether every cell of a row or a column is stored, so that the default cell does not take part in its layout
+    fn covers(&self, stored: usize, total: usize) -> bool {
+        self.default.is_none() || stored >= total
+    }
+}
+
+/// The widest integer and fraction parts of the numeric lines in a column aligned on the decimal separator
+#[derive(Copy, Clone)]
+struct DecimalParts {
+    separator: char,
+    integer: usize,
+    /// Includes the separator itself
+    fraction: usize,
+}
+
+impl DecimalParts {
+    /// Splits a line before the last decimal separator
+    fn split<'a>(&self, line: &'a str) -> (&'a str, &'a str) {
+        line.split_at(line.rfind(self.separator).unwrap_or(line.len()))
+    }
+
+    /// Pads a numeric line so that its decimal separator is at the same position as in all other lines of the column.
+    /// Other lines are returned unchanged
+    fn align(&self, line: &str) -> String {
+        if !is_numeric(line) {
+            return line.to_string();
+        }
+
+        let (integer, fraction) = self.split(line.trim());
+        let empty = "";
+        format!(
+            "{empty:left$}{integer}{fraction}{empty:right$}",
+            left = self.integer.saturating_sub(display_width(integer)),
+            right = self.fraction.saturating_sub(display_width(fraction)),
+        )
+    }
+
+    /// The width of every aligned numeric line
+    fn width(&self) -> usize {
+        self.integer + self.fraction
+    }
+}
+
+/// Only lines containing a digit take part in the decimal alignment, so that e.g. `-` or `n/a` are aligned as usual
+fn is_numeric(line: &str) -> bool {
+    line.chars().any(|c| c.is_ascii_digit())
+}
+
+/// Measures the integer and fraction parts of every column aligned on the decimal separator.
+/// Header rows are ignored, as well as the cells a sparse table does not store, since they never contain a number
+fn compute_decimal_parts(cells: &Cells, positions: &[(usize, usize)]) -> Vec<Option<DecimalParts>> {
+    let table = cells.table;
+    let mut parts: Vec<Option<DecimalParts>> = (0..table.get_column_count())
+        .map(|col| Some(DecimalParts { separator: table.get_decimal_alignment(col)?, integer: 0, fraction: 0 }))
+        .collect();
+
+    for &(row, col) in positions {
+        let Some(parts) = parts[col].as_mut().filter(|_| row >= table.get_header_rows()) else {
+            continue;
+        };
+        for line in cells.get(row, col).unwrap().get_wrapped_lines(table.get_column_width(col)) {
+            if is_numeric(&line) {
+                let (integer, fraction) = parts.split(line.trim());
+                parts.integer = parts.integer.max(display_width(integer));
+                parts.fraction = parts.fraction.max(display_width(fraction));
+            }
+        }
+    }
+
+    parts
+}
+
+/// Wraps the content of a single cell
+fn wrap_cell(cells: &Cells, row: usize, col: usize, cell: &FancyCell, decimal_parts: &[Option<DecimalParts>]) -> Vec<String> {
+    let table = cells.table;
+    if table.is_collapsed(row, col) {
+        return vec![String::new()];
+    }
+
+    let lines = cell.get_wrapped_lines(table.get_column_width(col));
+    match decimal_parts[col] {
+        Some(parts) if row >= table.get_header_rows() => lines.iter().map(|line| parts.align(line)).collect(),
+        _ => lines,
+    }
+}
+
+/// Wraps the content of every stored cell in the given rows.
+/// Returns the lines of the stored cells and the lines of the default cell of a sparse table for every column
+fn compute_lines(cells: &Cells, rows: Range<usize>, decimal_parts: &[Option<DecimalParts>]) -> (CellLines, Vec<Vec<String>>) {
+    let table = cells.table;
+    let positions = cells.positions(rows);
+    #[cfg(feature = "parallel")]
+    let positions = positions.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let positions = positions.into_iter();
+
+    let lines = positions
+        .filter_map(|(row, col)| Some(((row, col), wrap_cell(cells, row, col, cells.get(row, col)?, decimal_parts))))
+        .collect();
+
+    // unstored cells of a collapsed column are not replaced by a blank line, the blank default cell renders the same
+    let default_lines = (0..table.get_column_count())
+        .map(|col| match &cells.default {
+            Some(default) => default.get_wrapped_lines(table.get_column_width(col)),
+            None => vec![],
+        })
+        .collect();
+
+    (lines, default_lines)
+}
+
+/// Returns the height of every row in `rows`, taking the default cell into account for rows with unstored cells
+fn compute_row_heights(table: &FancyTable, rows: Range<usize>, lines: &CellLines, default_lines: &[Vec<String>]) -> Vec<usize> {
+    let mut heights = vec![0; rows.len()];
+    let mut stored = vec![0; rows.len()];
+    for (&(row, _), lines) in lines {
+        heights[row - rows.start] = heights[row - rows.start].max(lines.len());
+        stored[row - rows.start] += 1;
+    }
+
+    let default_height = default_lines.iter().map(Vec::len).max().unwrap_or(0);
+    for (height, stored) in heights.iter_mut().zip(stored) {
+        if stored < table.get_column_count() {
+            *height = (*height).max(default_height);
+        }
+    }
+    heights
+}
+
+/// Returns the width of the widest cell of every column
+fn compute_column_widths(cells: &Cells, positions: &[(usize, usize)], decimal_parts: &[Option<DecimalParts>]) -> Vec<usize> {
+    let table = cells.table;
+    let width = |col: usize, cell: &FancyCell| {
+        let column_width = table.get_column_width(col);
+        match decimal_parts[col] {
+            Some(parts) => cell.get_width(column_width).max(parts.width() + 2 * cell.get_padding(column_width)),
+            None => cell.get_width(column_width),
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    let measured: Vec<(usize, usize)> = positions.par_iter()
+        .filter_map(|&(row, col)| Some((col, width(col, cells.get(row, col)?))))
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let measured = positions.iter()
+        .filter_map(|&(row, col)| Some((col, width(col, cells.get(row, col)?))));
+
+    let mut widths = vec![0; table.get_column_count()];
+    let mut stored = vec![0; table.get_column_count()];
+    for (col, cell_width) in measured {
+        widths[col] = widths[col].max(cell_width);
+        stored[col] += 1;
+    }
+
+    if let Some(default) = &cells.default {
+        for col in 0..widths.len() {
+            if !cells.covers(stored[col], table.get_row_count()) {
+                widths[col] = widths[col].max(width(col, default));
+            }
+        }
+    }
+    widths
+}
+
+/// Widens the columns of every column group whose label does not fit above its columns
+fn widen_for_groups(table: &FancyTable, widths: &mut Vec<usize>) {
+    let spans: Vec<SpanConstraint> = group_segments(table.get_column_groups(), widths.len()).into_iter()
+        .map(|(columns, label)| {
+            // the label has a padding of 1 on each side,
+            // the solver expects separators of a single character between the columns, but gaps may be wider
+            let label_width = label.lines().map(|line| display_width(&table.get_escape_mode().apply(line))).max().unwrap_or(0) + 2;
+            let separators = (columns.start + 1..columns.end).map(|idx| table.get_separator_width(idx)).sum::<usize>();
+            SpanConstraint {
+                width: (label_width + columns.len() - 1).saturating_sub(separators),
+                columns,
+            }
+        })
+        .collect();
+
+    *widths = solve_widths(widths, &spans);
+}
@@ -1,6 +1,28 @@
 mod cell;
 mod table;
-mod style;
+mod layout;
+mod storage;
+mod validation;
+mod quick;
+mod pivot;
+mod group;
+mod export;
+mod responsive;
+mod solver;
+mod canvas;
+mod escape;
+mod parse;
+pub mod style;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 
 pub use cell::FancyCell;
-pub use table::FancyTable;
\ No newline at end of file
+pub use table::FancyTable;
+pub use storage::StorageKind;
+pub use group::ColumnGroup;
+pub use solver::{solve_widths, SpanConstraint};
+pub use canvas::{Canvas, CanvasCell};
+pub use escape::EscapeMode;
+pub use parse::ParseTableError;
+pub use validation::{ColumnCountError, ColumnType, ValidationError};
+pub use quick::{print_table, quick_table, QuickOptions};
\ No newline at end of file
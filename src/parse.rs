@@ -0,0 +1,179 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use crate::{Canvas, FancyCell, FancyTable};
+use crate::layout::display_width;
+use crate::style::border::BorderStyle;
+
+/// A table that could not be read by [FancyTable::parse]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ParseTableError {
+    /// The input does not contain any column borders
+    NoBorders,
+    /// The line with the given number, counted from 1, does not start and end at the outer borders of the table
+    MisalignedLine(usize),
+}
+
+impl Display for ParseTableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseTableError::NoBorders => write!(f, "no column borders found"),
+            ParseTableError::MisalignedLine(line) => write!(f, "line {line} does not match the borders of the table"),
+        }
+    }
+}
+
+impl Error for ParseTableError {}
+
+const HORIZONTAL_SYMBOLS: [&str; 7] = ["─", "═", "╴", "╶", "┄", "-", "="];
+
+const VERTICAL_SYMBOLS: [&str; 6] = ["│", "║", "╵", "╷", "┆", "|"];
+
+/// Returns whether a symbol is part of a border, using either box drawing symbols or ASCII
+fn is_border(symbol: &str) -> bool {
+    let mut chars = symbol.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => ('\u{2500}'..='\u{257F}').contains(&c) || "+-=|".contains(c),
+        _ => false,
+    }
+}
+
+/// A separator line only consists of borders and spaces and starts with a horizontal line, e.g. `+---+` or `├───┤`,
+/// while a row like `| - |` starts with the padding of its first cell
+fn is_separator(line: &[&str]) -> bool {
+    let mut symbols = line.iter().filter(|symbol| !symbol.is_empty());
+    let all_borders = symbols.clone().all(|symbol| *symbol == " " || is_border(symbol));
+    let starts_with_line = symbols.find(|symbol| **symbol != " ").is_some()
+        && symbols.next().is_some_and(|symbol| HORIZONTAL_SYMBOLS.contains(symbol));
+
+    all_borders && starts_with_line
+}
+
+/// Builds a cell from its lines, removing the padding and blank lines added by the alignment
+fn parse_cell(lines: &[String]) -> FancyCell {
+    let lines: Vec<&str> = lines.iter().map(|line| line.trim()).collect();
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+
+    if start == end {
+        FancyCell::default()
+    } else {
+        FancyCell::new(lines[start..end].join("\n"))
+    }
+}
+
+impl FancyTable {
+    /// Reads a table rendered by this crate or a simple ASCII table back into cells.
+    ///
+    /// The columns are found at the junctions of the separator lines, or at the `|` of the first line if there are none.
+    /// Consecutive lines between two separator lines form a single row with multiline cells,
+    /// if there are no separator lines, every line is a row.
+    /// The content of every line of a cell is trimmed and double separators are restored,
+    /// other styles, column groups and the header rows are not, the labels of column groups are skipped.
+    /// Blank lines, indentation and ANSI escape sequences are ignored
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let table = FancyTable::new(vec![
+    ///     vec!["Name".into(), "Scores".into()],
+    ///     vec!["Alice".into(), "12\n15".into()],
+    /// ]);
+    /// let parsed = FancyTable::parse(&table.to_string()).unwrap();
+    /// assert_eq!(parsed.get(1, 1).unwrap().get_content(), &vec!["12".to_string(), "15".to_string()]);
+    ///
+    /// let mut grouped = table.clone();
+    /// grouped.add_column_group("Group".into(), 0..2);
+    /// assert_eq!(FancyTable::parse(&grouped.to_string()).unwrap(), parsed);
+    ///
+    /// let parsed = FancyTable::parse("
+    ///     +-----+-----+
+    ///     | id  | ok  |
+    ///     +=====+=====+
+    ///     | 1   | yes |
+    ///     +-----+-----+
+    /// ").unwrap();
+    /// assert_eq!((parsed.get_row_count(), parsed.get_column_count()), (2, 2));
+    /// assert_eq!(parsed.get(1, 1).unwrap().get_content(), &vec!["yes".to_string()]);
+    /// ```
+    pub fn parse(input: &str) -> Result<FancyTable, ParseTableError> {
+        let lines: Vec<(usize, &str)> = input.lines()
+            .enumerate()
+            .filter(|(_, line)| display_width(line.trim()) > 0)
+            .collect();
+        let width = lines.iter().map(|(_, line)| display_width(line)).max().unwrap_or(0);
+
+        // writing the lines into a canvas strips the escape sequences and lines up wide characters
+        let mut grid = Canvas::new(width, lines.len());
+        for (y, (_, line)) in lines.iter().enumerate() {
+            grid.write_str(0, y, line);
+        }
+        let symbol = |x: usize, y: usize| grid.get(x, y).map_or(" ", |cell| cell.symbol.as_str());
+        let separators: Vec<bool> = (0..lines.len())
+            .map(|y| is_separator(&(0..width).map(|x| symbol(x, y)).collect::<Vec<&str>>()))
+            .collect();
+
+        let is_junction = |x: usize, y: usize| is_border(symbol(x, y)) && !HORIZONTAL_SYMBOLS.contains(&symbol(x, y));
+
+        // every junction of any separator line is a column border
+        let mut borders: BTreeSet<usize> = (0..lines.len())
+            .filter(|y| separators[*y])
+            .flat_map(|y| (0..width).filter(move |x| is_junction(*x, y)))
+            .collect();
+        if borders.is_empty() && !lines.is_empty() {
+            borders = (0..width).filter(|x| VERTICAL_SYMBOLS.contains(&symbol(*x, 0))).collect();
+        }
+        let borders: Vec<usize> = borders.into_iter().collect();
+        if borders.len() < 2 {
+            return Err(ParseTableError::NoBorders);
+        }
+        let (first, last) = (borders[0], borders[borders.len() - 1]);
+        // the labels of column groups are above the first separator with a junction at every column border
+        let start = (0..lines.len())
+            .find(|&y| separators[y] && borders.iter().all(|&x| is_junction(x, y)))
+            .unwrap_or(0);
+
+        // access: rows[row][col][line]
+        let mut rows: Vec<Vec<Vec<String>>> = vec![];
+        let mut current: Option<Vec<Vec<String>>> = None;
+        let mut double_separators = vec![];
+        for (y, (line_idx, _)) in lines.iter().enumerate().skip(start) {
+            if !is_border(symbol(first, y)) || !is_border(symbol(last, y)) {
+                return Err(ParseTableError::MisalignedLine(line_idx + 1));
+            }
+
+            if separators[y] {
+                rows.extend(current.take());
+                if (first..last).any(|x| matches!(symbol(x, y), "═" | "=")) {
+                    double_separators.push(rows.len());
+                }
+                continue;
+            }
+
+            let row = current.get_or_insert_with(|| vec![vec![]; borders.len() - 1]);
+            for (col, border) in borders.windows(2).enumerate() {
+                row[col].push((border[0] + 1..border[1]).map(|x| symbol(x, y)).collect());
+            }
+            // without separator lines, rows cannot span several lines
+            if !separators.contains(&true) {
+                rows.extend(current.take());
+            }
+        }
+        rows.extend(current);
+
+        let mut table = FancyTable::create(rows.iter()
+            .map(|row| row.iter().map(|lines| parse_cell(lines)).collect())
+            .collect());
+
+        for idx in double_separators {
+            table.set_horizontal_separator_style(idx, BorderStyle::Double);
+        }
+        for (idx, border) in borders.iter().enumerate() {
+            if (start..lines.len()).any(|y| !separators[y] && symbol(*border, y) == "║") {
+                table.set_vertical_separator_style(idx, BorderStyle::Double);
+            }
+        }
+
+        Ok(table)
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::ops::Add;
+use crate::{FancyCell, FancyTable};
+
+/// Returns the index of a key, inserting it if it has not been seen yet
+fn key_index<K: Display + Eq + Hash>(key: K, indices: &mut HashMap<K, usize>, labels: &mut Vec<String>) -> usize {
+    if let Some(idx) = indices.get(&key) {
+        return *idx;
+    }
+
+    labels.push(key.to_string());
+    indices.insert(key, labels.len() - 1);
+    labels.len() - 1
+}
+
+impl FancyTable {
+    /// Builds a crosstab table from a list of items.
+    ///
+    /// `f` maps every item to a row key, a column key and a value.
+    /// The table has a header row with the unique column keys and a first column with the unique row keys,
+    /// both in the order they first appear. Values with the same keys are summed up,
+    /// see [FancyTable::pivot_with] for other aggregations.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let sales = [("Alice", "Jan", 10), ("Bob", "Jan", 5), ("Alice", "Feb", 7), ("Alice", "Jan", 1)];
+    /// let table = FancyTable::pivot(sales, |(name, month, amount)| (name, month, amount));
+    /// // header row + 2 people, key column + 2 months
+    /// assert_eq!((table.get_row_count(), table.get_column_count()), (3, 3));
+    /// assert_eq!(table.get(1, 1).unwrap().get_content(), &vec!["11".to_string()]);
+    /// ```
+    pub fn pivot<I, R, C, V, F>(items: I, f: F) -> FancyTable
+    where
+        I: IntoIterator,
+        F: Fn(I::Item) -> (R, C, V),
+        R: Display + Eq + Hash,
+        C: Display + Eq + Hash,
+        V: Display + Add<Output = V>,
+    {
+        FancyTable::pivot_with(items, f, |a, b| a + b)
+    }
+
+    /// Builds a crosstab table like [FancyTable::pivot], combining values with the same keys using `aggregate`
+    pub fn pivot_with<I, R, C, V, F, A>(items: I, f: F, aggregate: A) -> FancyTable
+    where
+        I: IntoIterator,
+        F: Fn(I::Item) -> (R, C, V),
+        A: Fn(V, V) -> V,
+        R: Display + Eq + Hash,
+        C: Display + Eq + Hash,
+        V: Display,
+    {
+        let mut row_indices = HashMap::new();
+        let mut row_labels = Vec::new();
+        let mut col_indices = HashMap::new();
+        let mut col_labels = Vec::new();
+        let mut values: HashMap<(usize, usize), V> = HashMap::new();
+
+        for item in items {
+            let (row_key, col_key, value) = f(item);
+            let row = key_index(row_key, &mut row_indices, &mut row_labels);
+            let col = key_index(col_key, &mut col_indices, &mut col_labels);
+
+            let value = match values.remove(&(row, col)) {
+                Some(previous) => aggregate(previous, value),
+                None => value,
+            };
+            values.insert((row, col), value);
+        }
+
+        let mut cells = Vec::with_capacity(row_labels.len() + 1);
+        cells.push(std::iter::once(FancyCell::default())
+            .chain(col_labels.into_iter().map(FancyCell::from))
+            .collect());
+        for (row, label) in row_labels.into_iter().enumerate() {
+            let mut cells_of_row = vec![FancyCell::from(label)];
+            for col in 0..col_indices.len() {
+                cells_of_row.push(values.get(&(row, col))
+                    .map(|value| FancyCell::from(value.to_string()))
+                    .unwrap_or_default());
+            }
+            cells.push(cells_of_row);
+        }
+
+        let mut table = FancyTable::create(cells);
+        table.set_header_rows(1);
+        table
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt::Alignment;
+#[cfg(feature = "ansi")]
+use ansi_term::Style;
+use crate::{ColumnType, FancyTable};
+use crate::style::border::BorderStyle;
+
+/// Options for [print_table] and [quick_table]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct QuickOptions {
+    /// Treats the first row as the header, which is highlighted and separated by a double line
+    pub header: bool,
+    /// Aligns columns that only contain numbers to the right
+    pub auto_align: bool,
+    /// Limits the table to the width of the terminal using [FancyTable::set_max_width],
+    /// read from the `COLUMNS` environment variable or, with the `terminal` feature, detected from stdout
+    pub fit_to_terminal: bool,
+}
+
+impl Default for QuickOptions {
+    fn default() -> Self {
+        QuickOptions {
+            header: true,
+            auto_align: true,
+            fit_to_terminal: true,
+        }
+    }
+}
+
+/// Returns the width of the terminal, if known.
+/// The `COLUMNS` environment variable takes precedence over the size of the terminal
+fn terminal_width() -> Option<usize> {
+    if let Some(width) = std::env::var("COLUMNS").ok().and_then(|columns| columns.parse().ok()) {
+        return Some(width);
+    }
+
+    #[cfg(feature = "terminal")]
+    if let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() {
+        return Some(width as usize);
+    }
+    None
+}
+
+/// Builds and themes a table from 2D data in one call.
+///
+/// # Example
+/// ```
+/// use fancytable::{quick_table, QuickOptions};
+/// let table = quick_table(&[
+///     &["Item", "Price"],
+///     &["Coffee", "3.50"],
+///     &["Cake", "12"],
+/// ], QuickOptions::default());
+/// assert_eq!(table.get_header_rows(), 1);
+/// ```
+pub fn quick_table(data: &[&[&str]], options: QuickOptions) -> FancyTable {
+    let mut table = FancyTable::new(data.iter()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+        .collect());
+
+    let header_rows = if options.header { 1.min(table.get_row_count()) } else { 0 };
+    table.set_header_rows(header_rows);
+
+    if header_rows > 0 {
+        table.set_horizontal_separator_style(header_rows, BorderStyle::Double);
+        #[cfg(feature = "ansi")]
+        for col in 0..table.get_column_count() {
+            table.get_mut(0, col).unwrap().style = Style::new().bold();
+        }
+    }
+
+    if options.auto_align {
+        for col in 0..table.get_column_count() {
+            let numeric = (header_rows..table.get_row_count())
+                .map(|row| table.get(row, col).unwrap().get_content().join("\n"))
+                .all(|content| ColumnType::Float.accepts(&content));
+            if !numeric || header_rows == table.get_row_count() {
+                continue;
+            }
+
+            for row in 0..table.get_row_count() {
+                table.get_mut(row, col).unwrap().horizontal_alignment = Alignment::Right;
+            }
+        }
+    }
+
+    if options.fit_to_terminal {
+        table.set_max_width(terminal_width());
+    }
+
+    table
+}
+
+/// Builds, themes and prints a table from 2D data in one call.
+/// See [quick_table] for building the table without printing it
+///
+/// # Example
+/// ```
+/// use fancytable::{print_table, QuickOptions};
+/// print_table(&[
+///     &["Name", "Age"],
+///     &["Alice", "42"],
+/// ], QuickOptions::default());
+/// ```
+pub fn print_table(data: &[&[&str]], options: QuickOptions) {
+    println!("{}", quick_table(data, options));
+}
@@ -0,0 +1,152 @@
+use crate::FancyTable;
+use crate::layout::{display_width, Layout};
+use crate::style::ColumnWidth;
+
+/// The narrowest content a truncated column is shrunk to, e.g. `ab…`
+const MIN_TRUNCATED_WIDTH: usize = 3;
+
+/// Returns the rendered width of the given columns of a table, as selected by [FancyTable::select_columns].
+/// `widths` contains the width of every column of the table
+fn total_width(table: &FancyTable, columns: &[usize], widths: &[usize]) -> usize {
+    // every column is preceded by a separator, plus the right border of the table
+    columns.iter().map(|&col| widths[col] + table.get_separator_width(col)).sum::<usize>()
+        + if columns.is_empty() { 0 } else { table.get_separator_width(table.get_column_count()) }
+}
+
+/// Returns the rendered width of all columns of a table
+fn full_width(table: &FancyTable, widths: &[usize]) -> usize {
+    total_width(table, &(0..widths.len()).collect::<Vec<usize>>(), widths)
+}
+
+/// Returns the narrowest width a column can be wrapped to without breaking words, including the padding
+fn min_wrapped_width(table: &FancyTable, col: usize) -> usize {
+    let widest_word = (0..table.get_row_count())
+        .filter_map(|row| table.get(row, col))
+        .flat_map(|cell| cell.get_wrapped_lines(ColumnWidth::Dynamic))
+        .flat_map(|line| line.split_whitespace().map(display_width).collect::<Vec<usize>>())
+        .max()
+        .unwrap_or(0);
+
+    // a column keeps at least one character of content
+    widest_word.max(1) + 2
+}
+
+/// Shrinks the widest column above its minimum by one until the total width fits into `max_width`.
+/// Columns without a minimum are never shrunk
+fn shrink(table: &FancyTable, widths: &mut [usize], minimums: &[Option<usize>], max_width: usize) {
+    while full_width(table, widths) > max_width {
+        let widest = widths.iter()
+            .enumerate()
+            .filter(|(col, width)| minimums[*col].is_some_and(|min| **width > min))
+            .max_by_key(|(_, width)| **width);
+        let Some((col, _)) = widest else {
+            break;
+        };
+
+        widths[col] -= 1;
+    }
+}
+
+/// Wraps and then truncates the dynamic columns of a table until it fits into `max_width`.
+/// Returns whether the table fits
+fn shrink_to(table: &mut FancyTable, max_width: usize) -> bool {
+    let natural = Layout::compute(table).column_widths;
+    let dynamic: Vec<bool> = (0..natural.len())
+        .map(|col| table.get_column_width(col) == ColumnWidth::Dynamic)
+        .collect();
+    let wrapped: Vec<Option<usize>> = (0..natural.len())
+        .map(|col| dynamic[col].then(|| min_wrapped_width(table, col)))
+        .collect();
+    let truncated: Vec<Option<usize>> = dynamic.iter()
+        .map(|dynamic| dynamic.then_some(MIN_TRUNCATED_WIDTH + 2))
+        .collect();
+
+    let mut widths = natural.clone();
+    shrink(table, &mut widths, &wrapped, max_width);
+    shrink(table, &mut widths, &truncated, max_width);
+
+    for (col, &width) in widths.iter().enumerate() {
+        if wrapped[col].is_some_and(|min| width < min) {
+            table.set_column_width(col, ColumnWidth::Truncated(width - 2));
+        } else if width < natural[col] {
+            table.set_column_width(col, ColumnWidth::Fixed(width - 2));
+        }
+    }
+
+    full_width(table, &widths) <= max_width
+}
+
+impl FancyTable {
+    /// Splits a table that is wider than `max_width` into vertical slices of columns that fit,
+    /// each one starting with the frozen columns set by [FancyTable::set_frozen_columns].
+    /// Every slice contains at least one column that is not frozen, even if it is too wide
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["ID".into(), "January".into(), "February".into(), "March".into()],
+    ///     vec!["1".into(), "10".into(), "20".into(), "30".into()],
+    /// ]);
+    /// table.set_frozen_columns(1);
+    /// let slices = table.split_columns(25);
+    /// assert_eq!(slices.len(), 2);
+    /// assert_eq!(slices[1].get(0, 0).unwrap().get_content(), &vec!["ID".to_string()]);
+    /// ```
+    pub fn split_columns(&self, max_width: usize) -> Vec<FancyTable> {
+        let widths = Layout::compute(self).column_widths;
+        let frozen: Vec<usize> = (0..self.get_frozen_columns().min(widths.len())).collect();
+
+        let mut slices = vec![];
+        let mut columns = frozen.clone();
+        for col in frozen.len()..widths.len() {
+            let candidate: Vec<usize> = columns.iter().copied().chain([col]).collect();
+            if columns.len() > frozen.len() && total_width(self, &candidate, &widths) > max_width {
+                slices.push(self.select_columns(&columns));
+                columns = frozen.clone();
+            }
+            columns.push(col);
+        }
+
+        if columns.len() > frozen.len() || slices.is_empty() {
+            slices.push(self.select_columns(&columns));
+        }
+        slices
+    }
+
+    /// Renders the slices of [FancyTable::split_columns] one after another, separated by blank lines
+    pub fn render_sliced(&self, max_width: usize) -> String {
+        self.split_columns(max_width).iter()
+            .map(|slice| slice.to_string())
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    /// Applies the policy of [FancyTable::set_max_width] to a copy of this table.
+    /// Returns the copy and the number of hidden columns, or [None] if the table already fits
+    pub(crate) fn fit_to_max_width(&self) -> Option<(FancyTable, usize)> {
+        // the left margin is part of the budget
+        let max_width = self.get_max_width()?.saturating_sub(self.get_margin().0);
+        if full_width(self, &Layout::compute(self).column_widths) <= max_width {
+            return None;
+        }
+
+        let collapsible: Vec<usize> = (0..self.get_column_count())
+            .rev()
+            .filter(|&col| self.is_column_collapsible(col))
+            .collect();
+        let mut columns: Vec<usize> = (0..self.get_column_count()).collect();
+
+        let mut hidden = 0;
+        loop {
+            let mut view = self.select_columns(&columns);
+            view.set_max_width(None);
+            if shrink_to(&mut view, max_width) || hidden == collapsible.len() {
+                return Some((view, hidden));
+            }
+
+            columns.retain(|&col| col != collapsible[hidden]);
+            hidden += 1;
+        }
+    }
+}
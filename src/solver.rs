@@ -0,0 +1,68 @@
+use std::ops::Range;
+
+/// Requires the columns in `columns` to be at least `width` characters wide in total,
+/// including the separators between them. Used for content spanning several columns, e.g. a [ColumnGroup](crate::ColumnGroup)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SpanConstraint {
+    pub columns: Range<usize>,
+    pub width: usize,
+}
+
+/// Computes the width of every column from the minimum width of its own content and the constraints of spanning content.
+///
+/// Every column is at least as wide as its minimum.
+/// If a span is too narrow, the missing width is distributed across its columns in proportion to their current widths,
+/// so that wide columns grow more than narrow ones. Narrow spans are solved first, since wider spans containing them
+/// can then take their result into account. Spans outside the columns are ignored
+///
+/// # Example
+/// ```
+/// use fancytable::{solve_widths, SpanConstraint};
+/// // a label of 20 characters above two columns, separated by one character
+/// let widths = solve_widths(&[4, 12], &[SpanConstraint { columns: 0..2, width: 20 }]);
+/// assert_eq!(widths, vec![5, 14]);
+/// ```
+pub fn solve_widths(minimums: &[usize], spans: &[SpanConstraint]) -> Vec<usize> {
+    let mut widths = minimums.to_vec();
+
+    let mut spans: Vec<&SpanConstraint> = spans.iter()
+        .filter(|span| !span.columns.is_empty() && span.columns.end <= widths.len())
+        .collect();
+    spans.sort_by_key(|span| span.columns.len());
+
+    for span in spans {
+        let columns = &mut widths[span.columns.clone()];
+        let available = columns.iter().sum::<usize>() + columns.len() - 1;
+        if span.width > available {
+            distribute(columns, span.width - available);
+        }
+    }
+
+    widths
+}
+
+/// Adds `extra` to the widths in proportion to their current values.
+/// The remainder goes to the columns with the largest fractional shares, preferring the rightmost ones
+fn distribute(widths: &mut [usize], extra: usize) {
+    let total: usize = widths.iter().sum();
+    // equal weights if every column is empty
+    let weights: Vec<usize> = match total {
+        0 => vec![1; widths.len()],
+        _ => widths.to_vec(),
+    };
+    let total: usize = weights.iter().sum();
+
+    let mut remainders = Vec::with_capacity(widths.len());
+    let mut distributed = 0;
+    for (col, weight) in weights.iter().enumerate() {
+        let share = extra * weight;
+        widths[col] += share / total;
+        distributed += share / total;
+        remainders.push((share % total, col));
+    }
+
+    remainders.sort_by(|a, b| b.cmp(a));
+    for (_, col) in remainders.iter().take(extra - distributed) {
+        widths[*col] += 1;
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use crate::FancyCell;
+
+/// Selects how the cells of a [FancyTable](crate::FancyTable) are stored.
+/// Both kinds render identically.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum StorageKind {
+    /// Every cell is stored, even if it has never been set
+    #[default]
+    Dense,
+    /// Only cells that have been set or borrowed mutably are stored.
+    /// Useful for large tables where most cells are empty
+    Sparse,
+}
+
+/// The cells of a table
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) enum CellStorage {
+    /// Access: `cells[row][col]`
+    Dense(Vec<Vec<FancyCell>>),
+    Sparse {
+        /// Access: `cells[&(row, col)]`
+        cells: HashMap<(usize, usize), FancyCell>,
+        rows: usize,
+        columns: usize,
+        /// Returned for every cell that is not stored
+        default: FancyCell,
+    },
+}
+
+impl CellStorage {
+    /// Creates an empty storage of the given kind
+    pub fn new(kind: StorageKind) -> CellStorage {
+        match kind {
+            StorageKind::Dense => CellStorage::Dense(vec![]),
+            StorageKind::Sparse => CellStorage::Sparse {
+                cells: HashMap::new(),
+                rows: 0,
+                columns: 0,
+                default: FancyCell::default(),
+            },
+        }
+    }
+
+    pub fn kind(&self) -> StorageKind {
+        match self {
+            CellStorage::Dense(_) => StorageKind::Dense,
+            CellStorage::Sparse { .. } => StorageKind::Sparse,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        match self {
+            CellStorage::Dense(cells) => cells.len(),
+            CellStorage::Sparse { rows, .. } => *rows,
+        }
+    }
+
+    pub fn columns(&self) -> usize {
+        match self {
+            // since the table is always rectangular, this will always work
+            CellStorage::Dense(cells) => cells.first().map(Vec::len).unwrap_or(0),
+            CellStorage::Sparse { rows: 0, .. } => 0,
+            CellStorage::Sparse { columns, .. } => *columns,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&FancyCell> {
+        match self {
+            CellStorage::Dense(cells) => cells.get(row)?.get(col),
+            CellStorage::Sparse { cells, rows, columns, default } => {
+                if row >= *rows || col >= *columns {
+                    return None;
+                }
+                Some(cells.get(&(row, col)).unwrap_or(default))
+            }
+        }
+    }
+
+    /// Returns the stored cells in the given rows with their positions.
+    /// A dense storage stores every cell, a sparse storage only the cells that have been set or borrowed mutably
+    pub fn stored(&self, rows: Range<usize>) -> Box<dyn Iterator<Item = ((usize, usize), &FancyCell)> + '_> {
+        match self {
+            CellStorage::Dense(cells) => {
+                let rows = rows.start.min(cells.len())..rows.end.min(cells.len());
+                let start = rows.start;
+                Box::new(cells[rows].iter()
+                    .enumerate()
+                    .flat_map(move |(row, cells)| cells.iter()
+                        .enumerate()
+                        .map(move |(col, cell)| ((start + row, col), cell))))
+            }
+            CellStorage::Sparse { cells, .. } => Box::new(cells.iter()
+                .filter(move |((row, _), _)| rows.contains(row))
+                .map(|(&idx, cell)| (idx, cell))),
+        }
+    }
+
+    /// Returns the cell of every position a sparse storage does not store, [None] for a dense storage
+    pub fn default(&self) -> Option<&FancyCell> {
+        match self {
+            CellStorage::Dense(_) => None,
+            CellStorage::Sparse { default, .. } => Some(default),
+        }
+    }
+
+    /// Returns a mutable reference to the cell.
+    /// In a sparse storage, the cell will be stored from now on
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut FancyCell> {
+        match self {
+            CellStorage::Dense(cells) => cells.get_mut(row)?.get_mut(col),
+            CellStorage::Sparse { cells, rows, columns, .. } => {
+                if row >= *rows || col >= *columns {
+                    return None;
+                }
+                Some(cells.entry((row, col)).or_default())
+            }
+        }
+    }
+
+    /// Replaces a cell inside the bounds of the storage
+    pub fn set(&mut self, row: usize, col: usize, cell: FancyCell) {
+        match self {
+            CellStorage::Dense(cells) => cells[row][col] = cell,
+            CellStorage::Sparse { cells, .. } => {
+                cells.insert((row, col), cell);
+            }
+        }
+    }
+
+    /// Returns a storage of the same kind and size with every cell mapped
+    pub fn map(&self, f: impl Fn(&FancyCell) -> FancyCell) -> CellStorage {
+        match self {
+            CellStorage::Dense(cells) => CellStorage::Dense(cells.iter()
+                .map(|row| row.iter().map(&f).collect())
+                .collect()),
+            CellStorage::Sparse { cells, rows, columns, default } => CellStorage::Sparse {
+                cells: cells.iter().map(|(&idx, cell)| (idx, f(cell))).collect(),
+                rows: *rows,
+                columns: *columns,
+                default: f(default),
+            },
+        }
+    }
+
+    /// Returns a storage of the same kind containing only the given columns in the given order
+    pub fn select_columns(&self, selected: &[usize]) -> CellStorage {
+        match self {
+            CellStorage::Dense(cells) => CellStorage::Dense(cells.iter()
+                .map(|row| selected.iter().map(|&col| row[col].clone()).collect())
+                .collect()),
+            CellStorage::Sparse { cells, rows, default, .. } => CellStorage::Sparse {
+                cells: cells.iter()
+                    .flat_map(|(&(row, col), cell)| selected.iter()
+                        .enumerate()
+                        .filter(move |(_, &selected)| selected == col)
+                        .map(move |(new_col, _)| ((row, new_col), cell.clone())))
+                    .collect(),
+                rows: *rows,
+                columns: selected.len(),
+                default: default.clone(),
+            },
+        }
+    }
+
+    /// Appends a row of default cells
+    pub fn push_row(&mut self) {
+        let columns = self.columns();
+        match self {
+            CellStorage::Dense(cells) => cells.push(vec![FancyCell::default(); columns]),
+            CellStorage::Sparse { rows, .. } => *rows += 1,
+        }
+    }
+
+    /// Appends a column of default cells to every row
+    pub fn push_column(&mut self) {
+        match self {
+            CellStorage::Dense(cells) => {
+                for row in cells {
+                    row.push(FancyCell::default());
+                }
+            }
+            CellStorage::Sparse { columns, .. } => *columns += 1,
+        }
+    }
+}
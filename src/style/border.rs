@@ -1,4 +1,5 @@
-use crate::{FancyCell, FancyTable};
+use crate::FancyTable;
+use crate::style::profile::Charset;
 
 /// The thickness of a border row/column.
 /// Applies to the entire drawn line.
@@ -38,7 +39,7 @@ pub struct CellBorderStyle {
     pub bottom: BorderLineStyle,
 }
 
-fn get_horizontal_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String {
+pub(crate) fn get_horizontal_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String {
     match (line, style) {
         (BorderLineStyle::Solid, BorderStyle::Single) => "─",
         (BorderLineStyle::Dashed, BorderStyle::Single) => "╴",
@@ -48,7 +49,7 @@ fn get_horizontal_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String
     }.to_string()
 }
 
-fn get_vertical_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String {
+pub(crate) fn get_vertical_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String {
     match (line, style) {
         (BorderLineStyle::Solid, BorderStyle::Single) => "│",
         (BorderLineStyle::Dashed, BorderStyle::Single) => "╵",
@@ -59,26 +60,24 @@ fn get_vertical_symbol(line: &BorderLineStyle, style: &BorderStyle) -> String {
 }
 
 /// Returns border symbols of the given cell in order: top, left, right, bottom
-pub fn get_cell_border_symbols(table: &FancyTable, cell_row: usize, cell_col: usize) -> (String, String, String, String) {
+pub(crate) fn get_cell_border_symbols(table: &FancyTable, cell_row: usize, cell_col: usize) -> (String, String, String, String) {
     let row = cell_row as i64;
     let col = cell_col as i64;
 
-    let default_cell = FancyCell::default();
-
-    let cell_style = table.get(cell_row, cell_col).unwrap_or(&default_cell).border_style;
+    let cell_style = table.get_rendered_border_style(row, col).unwrap_or_default();
 
     // neighbour cells
-    let top_style = table.get_cell(row - 1, col).unwrap_or(&default_cell).border_style;
-    let left_style = table.get_cell(row, col - 1).unwrap_or(&default_cell).border_style;
-    let right_style = table.get(cell_row, cell_col + 1).unwrap_or(&default_cell).border_style;
-    let bottom_style = table.get(cell_row + 1, cell_col).unwrap_or(&default_cell).border_style;
+    let top_style = table.get_rendered_border_style(row - 1, col).unwrap_or_default();
+    let left_style = table.get_rendered_border_style(row, col - 1).unwrap_or_default();
+    let right_style = table.get_rendered_border_style(row, col + 1).unwrap_or_default();
+    let bottom_style = table.get_rendered_border_style(row + 1, col).unwrap_or_default();
 
     let default_style = BorderStyle::default();
     // separator styles
     let top_hor_style = table.get_horizontal_separator_style(cell_row).unwrap_or(&default_style);
     let bottom_hor_style = table.get_horizontal_separator_style(cell_row + 1).unwrap_or(&default_style);
-    let left_vert_style = table.get_vertical_separator_style(cell_col).unwrap_or(&default_style);
-    let right_vert_style = table.get_vertical_separator_style(cell_col + 1).unwrap_or(&default_style);
+    let left_vert_style = table.get_rendered_vertical_separator_style(cell_col).unwrap_or(&default_style);
+    let right_vert_style = table.get_rendered_vertical_separator_style(cell_col + 1).unwrap_or(&default_style);
 
     // separator symbols
     let top_symbol = get_horizontal_symbol(&cell_style.top.max(top_style.bottom), top_hor_style);
@@ -98,7 +97,8 @@ fn style_based_selection(hor_style: BorderStyle, vert_style: BorderStyle, ss: &s
     }.into()
 }
 
-fn get_center_symbol(top: bool, left: bool, right: bool, bottom: bool, hor_style: BorderStyle, vert_style: BorderStyle) -> String {
+/// Returns the symbol where the given lines meet
+pub(crate) fn get_center_symbol(top: bool, left: bool, right: bool, bottom: bool, hor_style: BorderStyle, vert_style: BorderStyle) -> String {
     match (top, left, right, bottom) {
         // none
         (false, false, false, false) => " ".into(),
@@ -109,7 +109,7 @@ fn get_center_symbol(top: bool, left: bool, right: bool, bottom: bool, hor_style
         // bottom t (┴)
         (true, true, true, false) => style_based_selection(hor_style, vert_style, "┴", "╧", "╨", "╩"),
         // left t (├)
-        (true, false, true, true) => style_based_selection(hor_style, vert_style, "├", "╞", "╨", "╟"),
+        (true, false, true, true) => style_based_selection(hor_style, vert_style, "├", "╞", "╟", "╠"),
         // right t (┤)
         (true, true, false, true) => style_based_selection(hor_style, vert_style, "┤", "╡", "╢", "╣"),
         // vertical line (│)
@@ -135,34 +135,50 @@ fn get_center_symbol(top: bool, left: bool, right: bool, bottom: bool, hor_style
     }
 }
 
-pub fn get_common_cell_border_symbol(top_left: Option<&FancyCell>, top_right: Option<&FancyCell>, bottom_left: Option<&FancyCell>, bottom_right: Option<&FancyCell>, hor_style: BorderStyle, vert_style: BorderStyle) -> String {
+/// Returns which lines meet at the common corner of four cells in order: top, left, right, bottom
+pub(crate) fn get_common_cell_border_arms(top_left: Option<CellBorderStyle>, top_right: Option<CellBorderStyle>, bottom_left: Option<CellBorderStyle>, bottom_right: Option<CellBorderStyle>) -> (bool, bool, bool, bool) {
     let top = match (top_left, top_right) {
-        (Some(left), Some(right)) => left.border_style.right.max(right.border_style.left) != BorderLineStyle::None,
-        (Some(left), None) => left.border_style.right != BorderLineStyle::None,
-        (None, Some(right)) => right.border_style.left != BorderLineStyle::None,
+        (Some(left), Some(right)) => left.right.max(right.left) != BorderLineStyle::None,
+        (Some(left), None) => left.right != BorderLineStyle::None,
+        (None, Some(right)) => right.left != BorderLineStyle::None,
         _ => false,
     };
 
     let left = match (top_left, bottom_left) {
-        (Some(top), Some(bot)) => top.border_style.bottom.max(bot.border_style.top) != BorderLineStyle::None,
-        (Some(top), None) => top.border_style.bottom != BorderLineStyle::None,
-        (None, Some(bot)) => bot.border_style.top != BorderLineStyle::None,
+        (Some(top), Some(bot)) => top.bottom.max(bot.top) != BorderLineStyle::None,
+        (Some(top), None) => top.bottom != BorderLineStyle::None,
+        (None, Some(bot)) => bot.top != BorderLineStyle::None,
         _ => false,
     };
 
     let right = match (top_right, bottom_right) {
-        (Some(top), Some(bot)) => top.border_style.bottom.max(bot.border_style.top) != BorderLineStyle::None,
-        (Some(top), None) => top.border_style.bottom != BorderLineStyle::None,
-        (None, Some(bot)) => bot.border_style.top != BorderLineStyle::None,
+        (Some(top), Some(bot)) => top.bottom.max(bot.top) != BorderLineStyle::None,
+        (Some(top), None) => top.bottom != BorderLineStyle::None,
+        (None, Some(bot)) => bot.top != BorderLineStyle::None,
         _ => false,
     };
 
     let bottom = match (bottom_left, bottom_right) {
-        (Some(left), Some(right)) => left.border_style.right.max(right.border_style.left) != BorderLineStyle::None,
-        (Some(left), None) => left.border_style.right != BorderLineStyle::None,
-        (None, Some(right)) => right.border_style.left != BorderLineStyle::None,
+        (Some(left), Some(right)) => left.right.max(right.left) != BorderLineStyle::None,
+        (Some(left), None) => left.right != BorderLineStyle::None,
+        (None, Some(right)) => right.left != BorderLineStyle::None,
         _ => false,
     };
 
-    get_center_symbol(top, left, right, bottom, hor_style, vert_style)
-}
\ No newline at end of file
+    (top, left, right, bottom)
+}
+
+/// Converts a symbol created by the functions above into the given [Charset]
+pub(crate) fn convert_symbol(symbol: String, charset: Charset) -> String {
+    if charset == Charset::Unicode {
+        return symbol;
+    }
+
+    match symbol.as_str() {
+        " " => " ",
+        "─" | "╴" | "╶" | "┄" => "-",
+        "═" => "=",
+        "│" | "╵" | "╷" | "┆" | "║" => "|",
+        _ => "+",
+    }.to_string()
+}
@@ -1,4 +1,10 @@
 pub mod border;
+pub mod profile;
+pub mod spec;
+
+#[cfg(feature = "ansi")]
+use ansi_term::Style;
+use crate::style::border::BorderStyle;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
 pub enum VerticalAlignment {
@@ -13,4 +19,100 @@ pub enum ColumnWidth {
     #[default]
     Dynamic,
     Fixed(usize),
-}
\ No newline at end of file
+    /// Like [ColumnWidth::Fixed], but longer lines are cut off and end with `…` instead of being wrapped
+    Truncated(usize),
+}
+
+/// The selected part of a table, highlighted when rendering
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Selection {
+    /// A whole row
+    Row(usize),
+    /// A whole column
+    Column(usize),
+    /// A single cell as (row, column)
+    Cell(usize, usize),
+}
+
+impl Selection {
+    /// Returns whether the cell at (row, col) is part of the selection
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        match *self {
+            Selection::Row(r) => r == row,
+            Selection::Column(c) => c == col,
+            Selection::Cell(r, c) => r == row && c == col,
+        }
+    }
+}
+
+/// The direction of a sorted column, see [FancyTable::set_sort_indicator](crate::FancyTable::set_sort_indicator)
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The symbols appended to the header of a sorted column, see [FancyTable::set_sort_glyphs](crate::FancyTable::set_sort_glyphs)
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SortGlyphs {
+    pub ascending: String,
+    pub descending: String,
+}
+
+impl SortGlyphs {
+    /// `▲` and `▼`, used with [Charset::Unicode](profile::Charset::Unicode)
+    pub fn unicode() -> SortGlyphs {
+        SortGlyphs { ascending: "▲".into(), descending: "▼".into() }
+    }
+
+    /// `^` and `v`, used with [Charset::Ascii](profile::Charset::Ascii)
+    pub fn ascii() -> SortGlyphs {
+        SortGlyphs { ascending: "^".into(), descending: "v".into() }
+    }
+
+    /// Returns the symbol for the given direction
+    pub fn get(&self, direction: SortDirection) -> &str {
+        match direction {
+            SortDirection::Ascending => &self.ascending,
+            SortDirection::Descending => &self.descending,
+        }
+    }
+}
+
+/// Combines two styles, where `over` is the more specific one.
+/// Colors of `over` replace the colors of `base`, attributes like bold are set if set in any of both
+#[cfg(feature = "ansi")]
+pub(crate) fn compose_styles(base: Style, over: Style) -> Style {
+    Style {
+        foreground: over.foreground.or(base.foreground),
+        background: over.background.or(base.background),
+        is_bold: base.is_bold || over.is_bold,
+        is_dimmed: base.is_dimmed || over.is_dimmed,
+        is_italic: base.is_italic || over.is_italic,
+        is_underline: base.is_underline || over.is_underline,
+        is_blink: base.is_blink || over.is_blink,
+        is_reverse: base.is_reverse || over.is_reverse,
+        is_hidden: base.is_hidden || over.is_hidden,
+        is_strikethrough: base.is_strikethrough || over.is_strikethrough,
+    }
+}
+
+/// How a column is called out by [FancyTable::emphasize_column_with](crate::FancyTable::emphasize_column_with)
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct ColumnEmphasis {
+    /// The style of the vertical separators on both sides of the column
+    pub separator_style: BorderStyle,
+    /// Combined with the style of the header cells of the column, if set
+    #[cfg(feature = "ansi")]
+    pub header_style: Option<Style>,
+}
+
+impl Default for ColumnEmphasis {
+    fn default() -> Self {
+        ColumnEmphasis {
+            separator_style: BorderStyle::Double,
+            #[cfg(feature = "ansi")]
+            header_style: None,
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::io::IsTerminal;
+
+/// The set of symbols used to draw the borders of a table
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Charset {
+    /// Unicode box drawing symbols
+    #[default]
+    Unicode,
+    /// Plain ASCII symbols (`+`, `-`, `=`, `|`)
+    Ascii,
+}
+
+/// Describes what the output a table is rendered to is capable of.
+///
+/// By default, [RenderProfile::detect] is used to choose between [RenderProfile::FANCY] for interactive terminals
+/// and [RenderProfile::PLAIN] when stdout is piped or redirected.
+/// The detection can be overridden per table using [FancyTable::set_render_profile](crate::FancyTable::set_render_profile)
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct RenderProfile {
+    pub charset: Charset,
+    /// Whether cell styles and ANSI escapes inside the content are written.
+    /// If disabled, escape sequences inside the content are stripped
+    pub colored: bool,
+}
+
+impl RenderProfile {
+    /// Unicode borders and colors, used for interactive terminals
+    pub const FANCY: RenderProfile = RenderProfile {
+        charset: Charset::Unicode,
+        colored: true,
+    };
+
+    /// ASCII borders without any colors, used when stdout is not a terminal
+    pub const PLAIN: RenderProfile = RenderProfile {
+        charset: Charset::Ascii,
+        colored: false,
+    };
+
+    /// Returns [RenderProfile::FANCY] if stdout is a terminal and [RenderProfile::PLAIN] otherwise
+    pub fn detect() -> RenderProfile {
+        if std::io::stdout().is_terminal() {
+            RenderProfile::FANCY
+        } else {
+            RenderProfile::PLAIN
+        }
+    }
+}
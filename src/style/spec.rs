@@ -0,0 +1,267 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+#[cfg(feature = "ansi")]
+use ansi_term::{Colour, Style};
+use crate::FancyCell;
+#[cfg(feature = "ansi")]
+use crate::style::compose_styles;
+use crate::style::border::{BorderLineStyle, BorderStyle, CellBorderStyle};
+
+/// A style spec, border style or line style that could not be parsed
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseStyleError {
+    /// The part of the input that is invalid
+    pub token: String,
+}
+
+impl ParseStyleError {
+    fn new(token: &str) -> ParseStyleError {
+        ParseStyleError { token: token.to_string() }
+    }
+}
+
+impl Display for ParseStyleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid style: {:?}", self.token)
+    }
+}
+
+impl Error for ParseStyleError {}
+
+impl FromStr for BorderStyle {
+    type Err = ParseStyleError;
+
+    /// Parses `single` or `double`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "single" => Ok(BorderStyle::Single),
+            "double" => Ok(BorderStyle::Double),
+            token => Err(ParseStyleError::new(token)),
+        }
+    }
+}
+
+impl FromStr for BorderLineStyle {
+    type Err = ParseStyleError;
+
+    /// Parses `solid`, `dashed`, `dotted` or `none`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "solid" => Ok(BorderLineStyle::Solid),
+            "dashed" => Ok(BorderLineStyle::Dashed),
+            "dotted" => Ok(BorderLineStyle::Dotted),
+            "none" => Ok(BorderLineStyle::None),
+            token => Err(ParseStyleError::new(token)),
+        }
+    }
+}
+
+impl FromStr for CellBorderStyle {
+    type Err = ParseStyleError;
+
+    /// Parses a comma separated list of line styles, e.g. `dotted,solid-top`.
+    /// A line style without a side applies to all sides, `<line>-<side>` to a single side
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = BorderLines::default();
+        for item in s.split(',') {
+            lines.set(item)?;
+        }
+
+        let mut style = CellBorderStyle::default();
+        lines.apply(&mut style);
+        Ok(style)
+    }
+}
+
+/// The line styles a [StyleSpec] sets on the sides of a cell.
+/// A side set to [None] keeps the line style of the cell
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct BorderLines {
+    pub top: Option<BorderLineStyle>,
+    pub left: Option<BorderLineStyle>,
+    pub right: Option<BorderLineStyle>,
+    pub bottom: Option<BorderLineStyle>,
+}
+
+impl BorderLines {
+    /// Sets the sides of a single `<line>` or `<line>-<side>` item
+    fn set(&mut self, item: &str) -> Result<(), ParseStyleError> {
+        let item = item.trim();
+        let Some((line, side)) = item.split_once('-') else {
+            let line = Some(item.parse()?);
+            *self = BorderLines { top: line, left: line, right: line, bottom: line };
+            return Ok(());
+        };
+
+        let line = Some(line.parse().map_err(|_| ParseStyleError::new(item))?);
+        match side {
+            "top" => self.top = line,
+            "left" => self.left = line,
+            "right" => self.right = line,
+            "bottom" => self.bottom = line,
+            _ => return Err(ParseStyleError::new(item)),
+        }
+        Ok(())
+    }
+
+    /// Overrides the sides of a border style that are set
+    pub fn apply(&self, style: &mut CellBorderStyle) {
+        let sides = [
+            (&mut style.top, self.top),
+            (&mut style.left, self.left),
+            (&mut style.right, self.right),
+            (&mut style.bottom, self.bottom),
+        ];
+        for (side, line) in sides {
+            if let Some(line) = line {
+                *side = line;
+            }
+        }
+    }
+}
+
+/// A color of a style spec, independent of the `ansi` feature
+#[derive(Copy, Clone)]
+#[cfg_attr(not(feature = "ansi"), allow(dead_code))]
+enum Color {
+    /// One of the 8 basic colors, in ANSI order
+    Named(u8),
+    Fixed(u8),
+    Rgb(u8, u8, u8),
+}
+
+const COLOR_NAMES: [&str; 8] = ["black", "red", "green", "yellow", "blue", "purple", "cyan", "white"];
+
+const ATTRIBUTES: [&str; 8] = ["bold", "dimmed", "italic", "underline", "blink", "reverse", "hidden", "strikethrough"];
+
+/// Parses a color name, a number of the 256 color palette or `#rrggbb`
+fn parse_color(token: &str) -> Option<Color> {
+    if let Some(idx) = COLOR_NAMES.iter().position(|name| *name == token) {
+        return Some(Color::Named(idx as u8));
+    }
+    if let Some(hex) = token.strip_prefix('#') {
+        let value = u32::from_str_radix(hex, 16).ok().filter(|_| hex.len() == 6)?;
+        return Some(Color::Rgb((value >> 16) as u8, (value >> 8) as u8, value as u8));
+    }
+    token.parse().ok().map(Color::Fixed)
+}
+
+#[cfg(feature = "ansi")]
+impl From<Color> for Colour {
+    fn from(value: Color) -> Self {
+        match value {
+            Color::Named(idx) => [
+                Colour::Black, Colour::Red, Colour::Green, Colour::Yellow,
+                Colour::Blue, Colour::Purple, Colour::Cyan, Colour::White,
+            ][idx as usize],
+            Color::Fixed(n) => Colour::Fixed(n),
+            Color::Rgb(r, g, b) => Colour::RGB(r, g, b),
+        }
+    }
+}
+
+/// A cell style parsed from a compact string, e.g. from a CLI flag or a config file.
+///
+/// The spec is a whitespace separated list of:
+/// - attributes: `bold`, `dimmed`, `italic`, `underline`, `blink`, `reverse`, `hidden`, `strikethrough`
+/// - a foreground color: `black`, `red`, `green`, `yellow`, `blue`, `purple`, `cyan`, `white`,
+///   a number of the 256 color palette or `#rrggbb`
+/// - a background color: `on-` followed by a color
+/// - borders: `border=` followed by a comma separated list of `single` or `double` for the [BorderStyle]
+///   and line styles as parsed by [CellBorderStyle], e.g. `border=double,dashed-top`
+///
+/// Without the `ansi` feature, attributes and colors are checked, but ignored
+///
+/// # Example
+/// ```
+/// use fancytable::FancyCell;
+/// use fancytable::style::border::{BorderLineStyle, BorderStyle};
+/// use fancytable::style::spec::StyleSpec;
+/// let spec: StyleSpec = "bold red on-black border=double,dashed-top".parse().unwrap();
+/// assert_eq!(spec.separator_style, Some(BorderStyle::Double));
+///
+/// let mut cell = FancyCell::new("Warning".into());
+/// cell.border_style.left = BorderLineStyle::Dotted;
+/// spec.apply(&mut cell);
+/// assert_eq!(cell.border_style.top, BorderLineStyle::Dashed);
+/// assert_eq!(cell.border_style.left, BorderLineStyle::Dotted);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct StyleSpec {
+    /// The text style, combined with the style of a cell by [StyleSpec::apply]
+    #[cfg(feature = "ansi")]
+    pub text: Style,
+    /// The style of the separators, which is set on the table, e.g. using [FancyTable::set_horizontal_separator_style](crate::FancyTable::set_horizontal_separator_style)
+    pub separator_style: Option<BorderStyle>,
+    /// The line styles of the borders of a cell, only the sides given by the spec are set
+    pub border: BorderLines,
+}
+
+impl StyleSpec {
+    /// Applies the text style and the border line styles set by the spec to a cell
+    pub fn apply(&self, cell: &mut FancyCell) {
+        #[cfg(feature = "ansi")]
+        {
+            cell.style = compose_styles(cell.style, self.text);
+        }
+        self.border.apply(&mut cell.border_style);
+    }
+
+    #[cfg(feature = "ansi")]
+    fn set_color(&mut self, color: Color, background: bool) {
+        if background {
+            self.text.background = Some(color.into());
+        } else {
+            self.text.foreground = Some(color.into());
+        }
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    fn set_color(&mut self, _: Color, _: bool) {}
+
+    #[cfg(feature = "ansi")]
+    fn set_attribute(&mut self, attribute: &str) {
+        let text = &mut self.text;
+        match attribute {
+            "bold" => text.is_bold = true,
+            "dimmed" => text.is_dimmed = true,
+            "italic" => text.is_italic = true,
+            "underline" => text.is_underline = true,
+            "blink" => text.is_blink = true,
+            "reverse" => text.is_reverse = true,
+            "hidden" => text.is_hidden = true,
+            _ => text.is_strikethrough = true,
+        }
+    }
+
+    #[cfg(not(feature = "ansi"))]
+    fn set_attribute(&mut self, _: &str) {}
+}
+
+impl FromStr for StyleSpec {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut spec = StyleSpec::default();
+
+        for token in s.split_whitespace() {
+            if let Some(borders) = token.strip_prefix("border=") {
+                for item in borders.split(',') {
+                    match item.parse::<BorderStyle>() {
+                        Ok(style) => spec.separator_style = Some(style),
+                        Err(_) => spec.border.set(item)?,
+                    }
+                }
+            } else if ATTRIBUTES.contains(&token) {
+                spec.set_attribute(token);
+            } else {
+                let background = token.strip_prefix("on-");
+                let color = parse_color(background.unwrap_or(token)).ok_or_else(|| ParseStyleError::new(token))?;
+                spec.set_color(color, background.is_some());
+            }
+        }
+
+        Ok(spec)
+    }
+}
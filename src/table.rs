@@ -1,20 +1,91 @@
 use std::cmp::max;
-use std::fmt::{Alignment, Display, Formatter};
-use crate::FancyCell;
-use crate::style::border::{BorderStyle, get_cell_border_symbols, get_common_cell_border_symbol};
-use crate::style::{ColumnWidth, VerticalAlignment};
+use std::ops::Range;
+use std::fmt::{Alignment, Display, Formatter, Write};
+use crate::{ColumnGroup, EscapeMode, FancyCell};
+use crate::group::group_segments;
+use crate::layout::{align_line, display_width, Layout};
+use crate::storage::{CellStorage, StorageKind};
+use crate::validation::{ColumnCountError, ColumnType, ValidationError};
+use crate::style::border::{BorderLineStyle, BorderStyle, CellBorderStyle, convert_symbol, get_cell_border_symbols, get_center_symbol, get_common_cell_border_arms, get_horizontal_symbol, get_vertical_symbol};
+use crate::style::profile::{Charset, RenderProfile};
+use crate::style::{ColumnEmphasis, ColumnWidth, Selection, SortDirection, SortGlyphs, VerticalAlignment};
+#[cfg(feature = "ansi")]
+use crate::style::compose_styles;
+#[cfg(feature = "ansi")]
+use ansi_term::Style;
 
 /// A stylizable, rectangular table for pretty cli output.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct FancyTable {
-    /// Access: `cells[row][col]`
-    cells: Vec<Vec<FancyCell>>,
+    cells: CellStorage,
     /// Fixes the column width, padding of cells will be set to 1
     column_widths: Vec<ColumnWidth>,
+    /// The expected type of data inside each column
+    column_types: Vec<ColumnType>,
+    /// The decimal separator each column is aligned on, if any
+    decimal_separators: Vec<Option<char>>,
+    /// Columns in which consecutive identical cells are merged
+    collapsed_columns: Vec<bool>,
+    /// Columns that may be hidden if the table is wider than [FancyTable::set_max_width] allows
+    collapsible_columns: Vec<bool>,
+    /// The width budget of the rendered table, if any
+    max_width: Option<usize>,
+    /// The number of columns at the left repeated in every slice of [FancyTable::split_columns]
+    frozen_columns: usize,
+    /// Below this width budget, the table is rendered by [FancyTable::to_plain_list]
+    plain_list_threshold: Option<usize>,
+    /// The number of rows at the top of the table that form the header
+    header_rows: usize,
+    /// The sorted column and its direction, shown in the last header row
+    sort_indicator: Option<(usize, SortDirection)>,
+    /// Overrides the symbols of the sort indicator, chosen by the charset otherwise
+    sort_glyphs: Option<SortGlyphs>,
+    /// The called out column and how it is called out, applied when rendering
+    column_emphasis: Option<(usize, ColumnEmphasis)>,
+    /// Labels spanning several columns above the first row
+    column_groups: Vec<ColumnGroup>,
     /// The vertical separators + borders
     vertical_separator_styles: Vec<BorderStyle>,
     /// The horizontal separators + border
     horizontal_separator_styles: Vec<BorderStyle>,
+    /// Whether each vertical separator is drawn
+    vertical_separators_visible: Vec<bool>,
+    /// The number of spaces each vertical separator is replaced by, if any
+    vertical_separator_gaps: Vec<Option<usize>>,
+    /// Whether each horizontal separator is drawn
+    horizontal_separators_visible: Vec<bool>,
+    /// Incremented on every change, see [FancyTable::get_changed_rows]
+    generation: u64,
+    /// The generation of the last change of every row
+    row_generations: Vec<u64>,
+    /// The generation of the last change affecting every row
+    table_generation: u64,
+    /// Fills the space between the content and the padding of a cell
+    padding_char: char,
+    /// How escape sequences and control characters inside the content are rendered
+    escape_mode: EscapeMode,
+    /// The number of spaces in front of every rendered line
+    margin_left: usize,
+    /// The number of blank lines above the rendered table
+    margin_top: usize,
+    /// The profile used for rendering, detected on every render if [None]
+    render_profile: Option<RenderProfile>,
+    /// The highlighted part of the table
+    selection: Option<Selection>,
+    /// The style used for selected cells
+    #[cfg(feature = "ansi")]
+    selection_style: Style,
+    /// The style every cell inherits
+    #[cfg(feature = "ansi")]
+    table_style: Style,
+    /// The styles the cells of each column inherit
+    #[cfg(feature = "ansi")]
+    column_styles: Vec<Style>,
+    /// The styles the cells of each row inherit
+    #[cfg(feature = "ansi")]
+    row_styles: Vec<Style>,
+    /// Whether selected cells use their own style with inverted colors instead of the selection style
+    selection_inverted: bool,
     /// Width settings for columns
     /// Set when adding a column to an empty table, so that a call on [FancyTable::add_rows] creates the correct result
     /// ONLY FOR INTERNAL USE!
@@ -40,18 +111,128 @@ impl FancyTable {
             }
         }
 
+        FancyTable::from_storage(CellStorage::Dense(cells))
+    }
+
+    /// Creates a table with default settings around the given cells
+    fn from_storage(cells: CellStorage) -> FancyTable {
+        let rows = cells.rows();
+        let columns = cells.columns();
         let vertical_separators: usize = max(columns + 1, 2);
-        let horizontal_separators: usize = max(cells.len() + 1, 2);
+        let horizontal_separators: usize = max(rows + 1, 2);
 
         FancyTable {
             column_widths: vec![ColumnWidth::default(); columns],
+            column_types: vec![ColumnType::default(); columns],
+            decimal_separators: vec![None; columns],
+            collapsed_columns: vec![false; columns],
+            collapsible_columns: vec![false; columns],
+            max_width: None,
+            plain_list_threshold: None,
+            frozen_columns: 0,
+            header_rows: 0,
+            sort_indicator: None,
+            sort_glyphs: None,
+            column_emphasis: None,
+            column_groups: vec![],
             vertical_separator_styles: vec![BorderStyle::default(); vertical_separators],
             horizontal_separator_styles: vec![BorderStyle::default(); horizontal_separators],
+            vertical_separators_visible: vec![true; vertical_separators],
+            vertical_separator_gaps: vec![None; vertical_separators],
+            horizontal_separators_visible: vec![true; horizontal_separators],
+            generation: 0,
+            row_generations: vec![0; rows],
+            table_generation: 0,
+            padding_char: ' ',
+            escape_mode: EscapeMode::default(),
+            margin_left: 0,
+            margin_top: 0,
+            render_profile: None,
+            selection: None,
+            #[cfg(feature = "ansi")]
+            selection_style: Style::new().reverse(),
+            #[cfg(feature = "ansi")]
+            table_style: Style::default(),
+            #[cfg(feature = "ansi")]
+            column_styles: vec![Style::default(); columns],
+            #[cfg(feature = "ansi")]
+            row_styles: vec![Style::default(); rows],
+            selection_inverted: false,
             _added_column_first: false,
             cells,
         }
     }
 
+    /// Creates an empty table using the given kind of storage.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::{FancyTable, StorageKind};
+    /// let mut table = FancyTable::with_storage(StorageKind::Sparse);
+    /// table.set(100_000, 50, "only this cell is stored".into());
+    /// assert_eq!(table.get_row_count(), 100_001);
+    /// ```
+    pub fn with_storage(kind: StorageKind) -> FancyTable {
+        FancyTable::from_storage(CellStorage::new(kind))
+    }
+
+    /// Returns a copy of this table with all settings and styles, but empty cells.
+    /// Useful to configure a template table once and fill it with successive datasets
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::border::BorderStyle;
+    /// let mut template = FancyTable::new(vec![vec!["Name".into(), "Score".into()]]);
+    /// template.set_horizontal_separator_style(1, BorderStyle::Double);
+    ///
+    /// let mut table = template.clone_layout();
+    /// assert_eq!(table.get(0, 0).unwrap().get_content(), &vec![" ".to_string()]);
+    /// assert_eq!(table.get_horizontal_separator_style(1), Some(&BorderStyle::Double));
+    /// table.set(0, 0, "Player".into());
+    /// ```
+    pub fn clone_layout(&self) -> FancyTable {
+        FancyTable {
+            cells: self.cells.map(FancyCell::clone_style),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the cells of this table without any styling.
+    /// The table settings, e.g. separators and column widths, are reset as well
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::border::BorderStyle;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into(), "Score".into()]]);
+    /// table.set_horizontal_separator_style(1, BorderStyle::Double);
+    /// table.get_mut(0, 0).unwrap().padding = 3;
+    ///
+    /// let data = table.clone_data();
+    /// assert_eq!(data.get(0, 0).unwrap().get_content(), &vec!["Name".to_string()]);
+    /// assert_eq!(data.get(0, 0).unwrap().padding, 1);
+    /// assert_eq!(data.get_horizontal_separator_style(1), Some(&BorderStyle::Single));
+    /// ```
+    pub fn clone_data(&self) -> FancyTable {
+        FancyTable::from_storage(self.cells.map(FancyCell::clone_content))
+    }
+
+    /// Returns the stored cells in the given rows with their positions, every cell of a dense table is stored
+    pub(crate) fn get_stored_cells(&self, rows: Range<usize>) -> Box<dyn Iterator<Item = ((usize, usize), &FancyCell)> + '_> {
+        self.cells.stored(rows)
+    }
+
+    /// Returns the cell of every position a sparse table does not store, [None] for a dense table
+    pub(crate) fn get_default_cell(&self) -> Option<&FancyCell> {
+        self.cells.default()
+    }
+
+    /// Returns the kind of storage used for the cells of this table
+    pub fn get_storage_kind(&self) -> StorageKind {
+        self.cells.kind()
+    }
+
     /// Creates a new table from a 2d-field of string
     /// The strings will be converted to [FancyCell]s
     /// Also, every row will have the same amount of columns after initialization
@@ -88,11 +269,83 @@ impl FancyTable {
             rows -= 1;
         }
 
-        let cols = self.cells.get(0).unwrap_or(&vec![].into()).len();
         for _ in 0..rows {
-            self.cells.push(vec![FancyCell::default(); cols]);
+            self.push_row();
+            // an empty table already has a top and a bottom border
+            if self.horizontal_separator_styles.len() < self.cells.rows() + 1 {
+                self.horizontal_separator_styles.push(BorderStyle::default());
+                self.horizontal_separators_visible.push(true);
+            }
+        }
+    }
+
+    /// Appends the rows of another table with the same number of columns, e.g. to combine the results of multiple workers.
+    /// The cells, row styles and horizontal separators of the other table are copied,
+    /// where the separators meeting between both tables are merged: a double line wins.
+    /// All other settings of the other table, e.g. its header rows or column widths, are ignored
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::{ColumnCountError, FancyTable};
+    /// let mut table = FancyTable::new(vec![vec!["a".into(), "1".into()]]);
+    /// let other = FancyTable::new(vec![vec!["b".into(), "2".into()], vec!["c".into(), "3".into()]]);
+    /// table.extend_from(&other).unwrap();
+    /// assert_eq!(table.get_row_count(), 3);
+    ///
+    /// let narrow = FancyTable::new(vec![vec!["d".into()]]);
+    /// assert_eq!(table.extend_from(&narrow), Err(ColumnCountError { expected: 2, found: 1 }));
+    /// ```
+    pub fn extend_from(&mut self, other: &FancyTable) -> Result<(), ColumnCountError> {
+        if other.get_column_count() != self.get_column_count() {
+            return Err(ColumnCountError {
+                expected: self.get_column_count(),
+                found: other.get_column_count(),
+            });
+        }
+
+        let offset = self.get_row_count();
+        if offset > 0 {
+            // the bottom border of this table becomes a separator
+            self.touch_row(offset - 1);
+        }
+
+        // an empty table may have more separators than rows + 1
+        self.horizontal_separator_styles.truncate(offset + 1);
+        self.horizontal_separators_visible.truncate(offset + 1);
+        if other.horizontal_separator_styles[0] == BorderStyle::Double {
+            self.horizontal_separator_styles[offset] = BorderStyle::Double;
+        }
+
+        for row in 0..other.get_row_count() {
+            self.push_row();
+            self.horizontal_separator_styles.push(other.horizontal_separator_styles[row + 1]);
+            self.horizontal_separators_visible.push(other.horizontal_separators_visible[row + 1]);
+            #[cfg(feature = "ansi")]
+            {
+                self.row_styles[offset + row] = other.row_styles[row];
+            }
+
+            for col in 0..self.get_column_count() {
+                self.cells.set(offset + row, col, other.get(row, col).unwrap().clone());
+            }
+        }
+
+        // there are always at least a top and a bottom border
+        if self.horizontal_separator_styles.len() < 2 {
             self.horizontal_separator_styles.push(BorderStyle::default());
+            self.horizontal_separators_visible.push(true);
         }
+        Ok(())
+    }
+
+    /// Appends a row of default cells and keeps the per-row settings in sync.
+    /// The horizontal separators are handled by [FancyTable::add_rows]
+    fn push_row(&mut self) {
+        self.cells.push_row();
+        self.generation += 1;
+        self.row_generations.push(self.generation);
+        #[cfg(feature = "ansi")]
+        self.row_styles.push(Style::default());
     }
 
     /// Adds a number of columns.
@@ -113,20 +366,72 @@ impl FancyTable {
     /// // this will result in a 2x2 table
     /// ```
     pub fn add_columns(&mut self, n: usize) {
-        if self.cells.len() == 0 {
-            self.cells.push(vec![]);
+        self.touch_all();
+        if self.cells.rows() == 0 {
+            self.push_row();
             self._added_column_first = true;
         }
 
         for _ in 0..n {
-            for row in &mut self.cells {
-                row.push(FancyCell::default());
+            self.cells.push_column();
+            if self.vertical_separator_styles.len() < self.cells.columns() + 1 {
+                self.vertical_separator_styles.push(BorderStyle::default());
+                self.vertical_separators_visible.push(true);
+                self.vertical_separator_gaps.push(None);
             }
-            self.vertical_separator_styles.push(BorderStyle::default());
             self.column_widths.push(ColumnWidth::default());
+            self.column_types.push(ColumnType::default());
+            self.decimal_separators.push(None);
+            self.collapsed_columns.push(false);
+            self.collapsible_columns.push(false);
+            #[cfg(feature = "ansi")]
+            self.column_styles.push(Style::default());
+        }
+    }
+
+    /// Appends a column with a header cell in the first row and the values below it.
+    /// Rows are added if needed, cells of rows without a value stay empty
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["Latency".into()], vec!["12ms".into()]]);
+    /// table.add_column_with("Throughput".into(), vec!["1.2k/s".into(), "0.9k/s".into()]);
+    /// assert_eq!(table.get_column_count(), 2);
+    /// assert_eq!(table.get_row_count(), 3);
+    /// ```
+    pub fn add_column_with(&mut self, header: String, values: Vec<String>) {
+        let col = self.get_column_count();
+        self.ensure_size(values.len() + 1, col + 1);
+
+        self.set(0, col, header.into());
+        for (row, value) in values.into_iter().enumerate() {
+            self.set(row + 1, col, value.into());
         }
     }
 
+    /// Creates a table from columns of a header and its values, e.g. the name of a metric and a series of measurements.
+    /// The first row is the header row, shorter columns are filled with empty cells
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let table = FancyTable::from_columns(vec![
+    ///     ("Latency".into(), vec!["12ms".into(), "15ms".into()]),
+    ///     ("Throughput".into(), vec!["1.2k/s".into()]),
+    /// ]);
+    /// assert_eq!(table.get_row_count(), 3);
+    /// assert_eq!(table.get_header_rows(), 1);
+    /// ```
+    pub fn from_columns(columns: Vec<(String, Vec<String>)>) -> FancyTable {
+        let mut table = FancyTable::create(vec![]);
+        for (header, values) in columns {
+            table.add_column_with(header, values);
+        }
+        table.set_header_rows(1);
+        table
+    }
+
     /// Sets the cell at a specified position starting at (0, 0)
     /// Will create rows and columns dynamically if needed.
     ///
@@ -139,22 +444,79 @@ impl FancyTable {
     /// table.set(5, 5, "Hello World".into()); // creates 6 rows and 6 columns
     /// ```
     pub fn set(&mut self, row_idx: usize, col_idx: usize, cell: FancyCell) -> &mut FancyCell {
-        if row_idx >= self.cells.len() {
-            self.add_rows(row_idx - self.cells.len() + 1);
+        self.ensure_size(row_idx + 1, col_idx + 1);
+
+        self.touch_row(row_idx);
+        self.cells.set(row_idx, col_idx, cell);
+        self.cells.get_mut(row_idx, col_idx).unwrap()
+    }
+
+    /// Sets many cells at once, creating rows and columns dynamically if needed.
+    /// The table is expanded only once, which is faster than calling [FancyTable::set] for every cell
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::create(vec![]);
+    /// table.set_many((0..100).map(|i| (i / 10, i % 10, i.to_string().into())));
+    /// assert_eq!(table.get_row_count(), 10);
+    /// assert_eq!(table.get_column_count(), 10);
+    /// ```
+    pub fn set_many(&mut self, cells: impl IntoIterator<Item = (usize, usize, FancyCell)>) {
+        let cells: Vec<(usize, usize, FancyCell)> = cells.into_iter().collect();
+        let rows = cells.iter().map(|(row, _, _)| row + 1).max().unwrap_or(0);
+        let columns = cells.iter().map(|(_, col, _)| col + 1).max().unwrap_or(0);
+        self.ensure_size(rows, columns);
+
+        for (row, col, cell) in cells {
+            self.touch_row(row);
+            self.cells.set(row, col, cell);
+        }
+    }
+
+    /// Sets every cell inside the given rows and columns to a copy of `cell`,
+    /// creating rows and columns dynamically if needed
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::default();
+    /// table.fill_region(1..3, 0..4, "n/a".into());
+    /// assert_eq!(table.get_row_count(), 3);
+    /// assert_eq!(table.get(2, 3).unwrap().get_content(), &vec!["n/a".to_string()]);
+    /// ```
+    pub fn fill_region(&mut self, rows: Range<usize>, columns: Range<usize>, cell: FancyCell) {
+        if rows.is_empty() || columns.is_empty() {
+            return;
         }
-        if col_idx >= self.cells[row_idx].len() {
-            self.add_columns(col_idx - self.cells[row_idx].len() + 1);
+        self.ensure_size(rows.end, columns.end);
+
+        for row in rows {
+            self.touch_row(row);
+            for col in columns.clone() {
+                self.cells.set(row, col, cell.clone());
+            }
         }
+    }
 
-        self.cells[row_idx][col_idx] = cell;
-        &mut self.cells[row_idx][col_idx]
+    /// Adds rows and columns until the table has at least the given size
+    fn ensure_size(&mut self, rows: usize, columns: usize) {
+        // adding the rows first prevents add_columns from adding a row to an empty table
+        if rows > self.cells.rows() {
+            // the row added by add_columns is counted by cells.rows() already,
+            // it is only given back to a later add_rows if it is not needed here
+            self._added_column_first = false;
+            self.add_rows(rows - self.cells.rows());
+        }
+        if columns > self.cells.columns() {
+            self.add_columns(columns - self.cells.columns());
+        }
     }
 
     /// Returns a reference to the [FancyCell] at the position (row_idx, col_idx)
     /// Returns [None] if not found
     pub fn get(&self, row_idx: usize, col_idx: usize) -> Option<&FancyCell> {
-        let row = self.cells.get(row_idx)?;
-        row.get(col_idx)
+        self.cells.get(row_idx, col_idx)
     }
 
     /// Returns a reference to the [FancyCell] at the(row_idx, col_idx) or [None] if not found
@@ -167,34 +529,121 @@ impl FancyTable {
         }
     }
 
-    /// Returns the maximum height of a given row
+    /// Returns the width of every column as it is rendered, including the padding of the cells but without the separators.
+    /// Takes the content of all cells, fixed widths and column groups into account.
+    /// The policy of [FancyTable::set_max_width] is not applied
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let table = FancyTable::new(vec![vec!["Hello".into(), "World!".into()]]);
+    /// assert_eq!(table.get_column_widths(), vec![7, 8]);
+    /// ```
+    pub fn get_column_widths(&self) -> Vec<usize> {
+        Layout::compute(self).column_widths
+    }
+
+    /// Returns the height of every row in lines as it is rendered, without the separators.
+    /// Unlike [FancyTable::get_row_height], this takes merged and lazy cells into account
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let table = FancyTable::new(vec![vec!["Hello\nWorld".into()], vec!["!".into()]]);
+    /// assert_eq!(table.get_row_heights(), vec![2, 1]);
+    /// ```
+    pub fn get_row_heights(&self) -> Vec<usize> {
+        let layout = Layout::compute(self);
+        (0..self.get_row_count()).map(|row| layout.row_height(row)).collect()
+    }
+
+    /// Returns the size of the rendered table as (width, height) in characters and lines,
+    /// including borders, margins and the policy of [FancyTable::set_max_width].
+    /// The table is rendered to measure it
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let table = FancyTable::new(vec![vec!["Hello".into(), "World!".into()]]);
+    /// assert_eq!(table.get_rendered_size(), (18, 3));
+    /// ```
+    pub fn get_rendered_size(&self) -> (usize, usize) {
+        let rendered = self.to_string();
+        let width = rendered.lines().map(display_width).max().unwrap_or(0);
+        (width, rendered.lines().count())
+    }
+
+    /// Returns the maximum height of a given row, based on the content of its cells and the column widths
     pub fn get_row_height(&self, row_idx: usize) -> usize {
-        self.cells[row_idx].iter()
-            .enumerate()
-            .map(|(col, cell)| cell.get_height(self.column_widths[col]))
+        (0..self.get_column_count())
+            .filter_map(|col| self.get(row_idx, col).map(|cell| cell.get_height(self.column_widths[col])))
             .max()
             .unwrap_or(0)
     }
 
     /// Returns a mutable reference to the [FancyCell] at the position (row_idx, col_idx) in the table
     /// Returns None if not found
+    /// The row is marked as changed, see [FancyTable::get_changed_rows]
     pub fn get_mut(&mut self, row_idx: usize, col_idx: usize) -> Option<&mut FancyCell> {
-        let row = self.cells.get_mut(row_idx)?;
-        row.get_mut(col_idx)
+        if row_idx < self.get_row_count() {
+            self.touch_row(row_idx);
+        }
+        self.cells.get_mut(row_idx, col_idx)
+    }
+
+    /// Returns the current generation of the table.
+    /// The generation is incremented on every change, store it after rendering to detect changes later
+    pub fn get_generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the rows that have changed after the given generation, e.g. to redraw only those using [FancyTable::render_row].
+    ///
+    /// Changes of the table that affect every row, like column widths or separator styles, return every row.
+    /// Borrowing a cell mutably using [FancyTable::get_mut] counts as a change, even if nothing is modified
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["a".into()], vec!["b".into()], vec!["c".into()]]);
+    /// let rendered = table.get_generation();
+    /// table.set(1, 0, "B".into());
+    /// assert_eq!(table.get_changed_rows(rendered), vec![1]);
+    /// ```
+    pub fn get_changed_rows(&self, since: u64) -> Vec<usize> {
+        let all_changed = self.table_generation > since;
+        self.row_generations.iter()
+            .enumerate()
+            .filter(|(_, generation)| all_changed || **generation > since)
+            .map(|(row, _)| row)
+            .collect()
+    }
+
+    /// Marks a single row as changed
+    fn touch_row(&mut self, row_idx: usize) {
+        self.generation += 1;
+        self.row_generations[row_idx] = self.generation;
+
+        // the next row might be merged into this one
+        if self.collapsed_columns.contains(&true) && row_idx + 1 < self.row_generations.len() {
+            self.row_generations[row_idx + 1] = self.generation;
+        }
+    }
+
+    /// Marks every row as changed
+    fn touch_all(&mut self) {
+        self.generation += 1;
+        self.table_generation = self.generation;
     }
 
     /// Returns the amount of rows currently in the table
     pub fn get_row_count(&self) -> usize {
-        self.cells.len()
+        self.cells.rows()
     }
 
     /// Returns the amount of columns currently in the table
     pub fn get_column_count(&self) -> usize {
-        if self.cells.len() != 0 {
-            // since the table is always rectangular, this will always work
-            return self.cells[0].len();
-        }
-        0
+        self.cells.columns()
     }
 
     /// Returns the style for a single vertical separator (not the outline)
@@ -209,100 +658,934 @@ impl FancyTable {
 
     /// Sets the style for a vertical separator (not the outline).
     pub fn set_vertical_separator_style(&mut self, idx: usize, style: BorderStyle) {
+        self.touch_all();
         self.vertical_separator_styles[idx] = style;
     }
 
     /// Sets the style for a horizontal separator (not the outline).
     pub fn set_horizontal_separator_style(&mut self, idx: usize, style: BorderStyle) {
+        self.touch_all();
         self.horizontal_separator_styles[idx] = style;
     }
 
+    /// Shows or hides the horizontal separator above the row `idx`, e.g. to join two rows forming one logical record.
+    /// Hidden separators between rows are not rendered at all, hidden outer borders are rendered as blank lines.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["first line".into()], vec!["second line".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_row_separator_visible(1, false);
+    /// assert_eq!(table.to_string().lines().count(), 4);
+    /// ```
+    pub fn set_row_separator_visible(&mut self, idx: usize, visible: bool) {
+        self.touch_all();
+        self.horizontal_separators_visible[idx] = visible;
+    }
+
+    /// Returns whether the horizontal separator above the row `idx` is drawn
+    pub fn is_row_separator_visible(&self, idx: usize) -> bool {
+        self.horizontal_separators_visible.get(idx).copied().unwrap_or(true)
+    }
+
+    /// Shows or hides the vertical separator left of the column `idx`.
+    /// Hidden separators are rendered as blank space
+    pub fn set_column_separator_visible(&mut self, idx: usize, visible: bool) {
+        self.touch_all();
+        self.vertical_separators_visible[idx] = visible;
+    }
+
+    /// Returns whether the vertical separator left of the column `idx` is drawn
+    pub fn is_column_separator_visible(&self, idx: usize) -> bool {
+        self.vertical_separators_visible.get(idx).copied().unwrap_or(true)
+    }
+
+    /// Replaces the vertical separator left of the column `idx` by `gap` spaces, e.g. for `ps`-style output without borders.
+    /// Horizontal separators crossing the gap are continued through it.
+    /// Passing [None] restores the separator with its style
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["NAME".into(), "STATUS".into(), "AGE".into()],
+    ///     vec!["web-1".into(), "Running".into(), "3d".into()],
+    /// ]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_column_separator_gap(0, Some(0));
+    /// table.set_column_separator_gap(1, Some(2));
+    /// table.set_column_separator_gap(2, Some(2));
+    /// table.set_column_separator_gap(3, Some(0));
+    /// assert_eq!(table.render_row(1), " web-1    Running    3d  ");
+    /// assert_eq!(table.to_string().lines().next(), Some("-------------------------"));
+    /// ```
+    pub fn set_column_separator_gap(&mut self, idx: usize, gap: Option<usize>) {
+        self.touch_all();
+        self.vertical_separator_gaps[idx] = gap;
+    }
+
+    /// Returns the number of spaces the vertical separator left of the column `idx` is replaced by, if any
+    pub fn get_column_separator_gap(&self, idx: usize) -> Option<usize> {
+        self.vertical_separator_gaps.get(idx).copied().flatten()
+    }
+
+    /// Returns the rendered width of the vertical separator left of the column `idx`
+    pub(crate) fn get_separator_width(&self, idx: usize) -> usize {
+        self.get_column_separator_gap(idx).unwrap_or(1)
+    }
+
+    /// Returns the vertical separator left of the column `idx` as it is rendered, either the given symbol or the gap
+    fn render_vertical_separator(&self, idx: usize, symbol: String, profile: &RenderProfile) -> String {
+        match self.get_column_separator_gap(idx) {
+            Some(gap) => " ".repeat(gap),
+            None => convert_symbol(symbol, profile.charset),
+        }
+    }
+
     /// Sets the width for an entire column.
     /// When printing, the padding of cells will be ignored and set to exactly 1
     pub fn set_column_width(&mut self, column: usize, column_width: ColumnWidth) {
+        self.touch_all();
         self.column_widths[column] = column_width;
     }
-}
 
-impl FancyTable {
-    fn get_col_widths(&self) -> Vec<usize> {
-        let columns = self.get_column_count();
-        let mut widths = Vec::with_capacity(columns);
+    /// Returns the width setting of a column
+    pub fn get_column_width(&self, column: usize) -> ColumnWidth {
+        self.column_widths[column]
+    }
+
+    /// Sets a width budget for the rendered table, applied every time the table is rendered without changing its settings.
+    /// If the table is too wide, the following steps are taken until it fits:
+    /// 1. columns with a [ColumnWidth::Dynamic] width are wrapped, but words are kept intact
+    /// 2. these columns are shrunk further and their lines are truncated, see [ColumnWidth::Truncated]
+    /// 3. columns marked by [FancyTable::set_column_collapsible] are hidden, starting at the rightmost one,
+    ///    and a note like `(2 columns hidden)` is printed below the table
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into(), "Description".into(), "Last modified".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_column_collapsible(2, true);
+    /// table.set_max_width(Some(16));
+    /// assert_eq!(table.to_string(), "+------+-------+\n| Name | Desc… |\n+------+-------+\n(1 column hidden)");
+    /// ```
+    pub fn set_max_width(&mut self, max_width: Option<usize>) {
+        self.touch_all();
+        self.max_width = max_width;
+    }
+
+    /// Returns the width budget of the rendered table
+    pub fn get_max_width(&self) -> Option<usize> {
+        self.max_width
+    }
+
+    /// Renders the table as a plain list, see [FancyTable::to_plain_list],
+    /// if the width budget set by [FancyTable::set_max_width] is below `threshold`.
+    /// Passing [None] always renders the table as a table
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into()], vec!["Alice".into()]]);
+    /// table.set_header_rows(1);
+    /// table.set_plain_list_threshold(Some(40));
+    /// table.set_max_width(Some(30));
+    /// assert_eq!(table.to_string(), "Name: Alice");
+    /// ```
+    pub fn set_plain_list_threshold(&mut self, threshold: Option<usize>) {
+        self.touch_all();
+        self.plain_list_threshold = threshold;
+    }
+
+    /// Returns the width budget below which the table is rendered as a plain list
+    pub fn get_plain_list_threshold(&self) -> Option<usize> {
+        self.plain_list_threshold
+    }
+
+    /// Freezes the first `columns` columns, e.g. an ID or name column, so that they are repeated
+    /// at the left of every slice created by [FancyTable::split_columns]
+    pub fn set_frozen_columns(&mut self, columns: usize) {
+        self.frozen_columns = columns;
+    }
+
+    /// Returns the number of frozen columns at the left of the table
+    pub fn get_frozen_columns(&self) -> usize {
+        self.frozen_columns
+    }
+
+    /// Marks a column as less important, so that it is hidden if the table does not fit into [FancyTable::set_max_width]
+    pub fn set_column_collapsible(&mut self, column: usize, collapsible: bool) {
+        self.touch_all();
+        self.collapsible_columns[column] = collapsible;
+    }
+
+    /// Returns whether a column may be hidden if the table is too wide
+    pub fn is_column_collapsible(&self, column: usize) -> bool {
+        self.collapsible_columns[column]
+    }
+
+    /// Returns a copy of this table containing only the given columns in the given order.
+    /// All per-column settings, separators, column groups and the selection are carried over
+    pub(crate) fn select_columns(&self, columns: &[usize]) -> FancyTable {
+        fn pick<T: Clone>(values: &[T], columns: &[usize]) -> Vec<T> {
+            columns.iter().map(|&col| values[col].clone()).collect()
+        }
+
+        // the separators left of every column, plus the right border of the table
+        let separators: Vec<usize> = if columns.is_empty() {
+            vec![0, self.get_column_count()]
+        } else {
+            columns.iter().copied().chain([self.get_column_count()]).collect()
+        };
+        // the new index of an old column
+        let new_index = |col: usize| columns.iter().position(|&c| c == col);
+
+        FancyTable {
+            cells: self.cells.select_columns(columns),
+            column_widths: pick(&self.column_widths, columns),
+            column_types: pick(&self.column_types, columns),
+            decimal_separators: pick(&self.decimal_separators, columns),
+            collapsed_columns: pick(&self.collapsed_columns, columns),
+            collapsible_columns: pick(&self.collapsible_columns, columns),
+            column_groups: self.column_groups.iter()
+                .filter_map(|group| {
+                    let selected: Vec<usize> = group.columns.clone().filter_map(new_index).collect();
+                    Some(ColumnGroup {
+                        label: group.label.clone(),
+                        columns: *selected.iter().min()?..selected.iter().max()? + 1,
+                    })
+                })
+                .collect(),
+            vertical_separator_styles: pick(&self.vertical_separator_styles, &separators),
+            vertical_separators_visible: pick(&self.vertical_separators_visible, &separators),
+            vertical_separator_gaps: pick(&self.vertical_separator_gaps, &separators),
+            selection: match self.selection {
+                Some(Selection::Column(col)) => new_index(col).map(Selection::Column),
+                Some(Selection::Cell(row, col)) => new_index(col).map(|col| Selection::Cell(row, col)),
+                selection => selection,
+            },
+            sort_indicator: self.sort_indicator
+                .and_then(|(col, direction)| Some((new_index(col)?, direction))),
+            column_emphasis: self.column_emphasis
+                .and_then(|(col, emphasis)| Some((new_index(col)?, emphasis))),
+            #[cfg(feature = "ansi")]
+            column_styles: pick(&self.column_styles, columns),
+            ..self.clone()
+        }
+    }
+
+    /// Calls out a column, e.g. the current sort key, by drawing double lines on both of its sides.
+    /// See [FancyTable::emphasize_column_with] for other styles
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into(), "Score".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::FANCY));
+    /// table.emphasize_column(1);
+    /// assert_eq!(table.render_row(0), "│ Name ║ Score ║");
+    ///
+    /// // only a single column is called out at a time
+    /// table.emphasize_column(0);
+    /// assert_eq!(table.render_row(0), "║ Name ║ Score │");
+    /// ```
+    pub fn emphasize_column(&mut self, column: usize) {
+        self.emphasize_column_with(column, ColumnEmphasis::default());
+    }
+
+    /// Calls out a column by changing the vertical separators on both of its sides
+    /// and optionally styling its header cells (see [FancyTable::set_header_rows]), or the first row if there are no header rows.
+    /// The emphasis replaces the emphasis of any other column and is applied when rendering, without changing the separators or cells
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "ansi")] {
+    /// use ansi_term::Style;
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::ColumnEmphasis;
+    /// use fancytable::style::border::BorderStyle;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into(), "Score".into()], vec!["Alice".into(), "42".into()]]);
+    /// let emphasis = ColumnEmphasis { separator_style: BorderStyle::Single, header_style: Some(Style::new().bold()) };
+    /// table.emphasize_column_with(1, emphasis);
+    /// assert!(table.get_rendered_style(0, 1).is_bold);
+    /// assert!(!table.get_rendered_style(1, 1).is_bold);
+    ///
+    /// table.emphasize_column_with(0, emphasis);
+    /// assert!(table.get_rendered_style(0, 0).is_bold);
+    /// assert!(!table.get_rendered_style(0, 1).is_bold);
+    /// # }
+    /// ```
+    pub fn emphasize_column_with(&mut self, column: usize, emphasis: ColumnEmphasis) {
+        self.touch_all();
+        self.column_emphasis = Some((column, emphasis));
+    }
+
+    /// Removes the emphasis of the called out column
+    pub fn clear_column_emphasis(&mut self) {
+        self.touch_all();
+        self.column_emphasis = None;
+    }
+
+    /// Returns the called out column and how it is called out, see [FancyTable::emphasize_column_with]
+    pub fn get_column_emphasis(&self) -> Option<(usize, ColumnEmphasis)> {
+        self.column_emphasis
+    }
+
+    /// Returns the style a vertical separator is rendered with, which is replaced next to the called out column
+    pub(crate) fn get_rendered_vertical_separator_style(&self, idx: usize) -> Option<&BorderStyle> {
+        let style = self.vertical_separator_styles.get(idx)?;
+        match &self.column_emphasis {
+            Some((col, emphasis)) if idx == *col || idx == col + 1 => Some(&emphasis.separator_style),
+            _ => Some(style),
+        }
+    }
+
+    /// Aligns the numeric cells of a column on the given decimal separator, so that their fraction digits line up
+    /// even if the precision varies. Passing [None] restores the normal alignment.
+    ///
+    /// Only lines containing a digit are aligned, header rows are left as they are.
+    /// The aligned numbers are positioned inside the cells according to their horizontal alignment
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["12.5".into()], vec!["1024".into()], vec!["0.125".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_decimal_alignment(0, Some('.'));
+    /// assert_eq!(table.render_row(0), "|   12.5   |");
+    /// assert_eq!(table.render_row(1), "| 1024     |");
+    /// assert_eq!(table.render_row(2), "|    0.125 |");
+    /// ```
+    pub fn set_decimal_alignment(&mut self, column: usize, separator: Option<char>) {
+        self.touch_all();
+        self.decimal_separators[column] = separator;
+    }
+
+    /// Returns the decimal separator a column is aligned on
+    pub fn get_decimal_alignment(&self, column: usize) -> Option<char> {
+        self.decimal_separators.get(column).copied().flatten()
+    }
+
+    /// Visually merges consecutive identical cells in a column when rendering.
+    /// Repeated cells are left blank and the horizontal separators between them are removed,
+    /// giving a grouped output for sorted data. Header rows and lazy cells are never merged
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["fruit".into(), "apple".into()],
+    ///     vec!["fruit".into(), "banana".into()],
+    ///     vec!["vegetable".into(), "carrot".into()],
+    /// ]);
+    /// table.collapse_repeated(0);
+    /// ```
+    pub fn collapse_repeated(&mut self, column: usize) {
+        self.touch_all();
+        self.collapsed_columns[column] = true;
+    }
+
+    /// Returns whether a cell is merged into the cell above by [FancyTable::collapse_repeated]
+    pub fn is_collapsed(&self, row_idx: usize, col_idx: usize) -> bool {
+        if !self.collapsed_columns.get(col_idx).copied().unwrap_or(false) || row_idx <= self.header_rows {
+            return false;
+        }
+
+        match (self.get(row_idx - 1, col_idx), self.get(row_idx, col_idx)) {
+            (Some(above), Some(cell)) => !above.is_lazy() && !cell.is_lazy() && above.get_content() == cell.get_content(),
+            _ => false,
+        }
+    }
+
+    /// Returns the border style of the cell at (row_idx, col_idx) as it is rendered,
+    /// e.g. without the borders between collapsed cells or along hidden separators.
+    /// Returns [None] if the cell does not exist or any variable is negative
+    pub fn get_rendered_border_style(&self, row: i64, col: i64) -> Option<CellBorderStyle> {
+        let mut style = self.get_cell(row, col)?.border_style;
+        let (row, col) = (row as usize, col as usize);
+
+        if self.is_collapsed(row, col) {
+            style.top = BorderLineStyle::None;
+        }
+        if self.is_collapsed(row + 1, col) {
+            style.bottom = BorderLineStyle::None;
+        }
 
-        for i in 0..columns {
-            let width = self.cells.iter()
-                .map(|row| row[i].get_width(self.column_widths[i]))
-                .max()
-                .unwrap_or(0);
-            widths.push(width);
+        if !self.is_row_separator_visible(row) {
+            style.top = BorderLineStyle::None;
+        }
+        if !self.is_row_separator_visible(row + 1) {
+            style.bottom = BorderLineStyle::None;
+        }
+        if !self.is_column_separator_visible(col) {
+            style.left = BorderLineStyle::None;
+        }
+        if !self.is_column_separator_visible(col + 1) {
+            style.right = BorderLineStyle::None;
         }
 
-        widths
+        Some(style)
     }
 
-    /// Writes the top border of a single row to the formatter
-    fn write_top_border(&self, f: &mut Formatter<'_>, row_idx: usize, widths: &Vec<usize>) -> std::fmt::Result {
+    /// Sets the number of rows at the top of the table that form the header.
+    /// Header rows are e.g. skipped by [FancyTable::validate]
+    pub fn set_header_rows(&mut self, rows: usize) {
+        self.touch_all();
+        self.header_rows = rows;
+    }
+
+    /// Returns the number of rows at the top of the table that form the header
+    pub fn get_header_rows(&self) -> usize {
+        self.header_rows
+    }
+
+    /// Shows that the table is sorted by a column by appending `▲` or `▼` to its header cell when rendering.
+    /// The content of the cell is not changed, but the column is widened to fit the indicator.
+    /// In a column with [ColumnWidth::Fixed] or [ColumnWidth::Truncated], the first line of the header is cut off instead.
+    /// The indicator is shown in the last header row, or the first row if there are no header rows
+    ///
+    /// With [Charset::Ascii](crate::style::profile::Charset::Ascii), `^` and `v` are used instead,
+    /// see [FancyTable::set_sort_glyphs] to choose other symbols
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::{ColumnWidth, SortDirection};
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Name".into(), "Age".into()], vec!["Alice".into(), "30".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_header_rows(1);
+    /// table.set_sort_indicator(1, SortDirection::Descending);
+    /// assert_eq!(table.render_row(0), "| Name  | Age v |");
+    /// assert_eq!(table.get(0, 1).unwrap().get_content(), &vec!["Age".to_string()]);
+    ///
+    /// table.set_column_width(0, ColumnWidth::Fixed(5));
+    /// table.set_sort_indicator(0, SortDirection::Ascending);
+    /// assert_eq!(table.render_row(0), "| Na… ^ | Age |");
+    /// ```
+    pub fn set_sort_indicator(&mut self, column: usize, direction: SortDirection) {
+        self.touch_all();
+        self.sort_indicator = Some((column, direction));
+    }
+
+    /// Removes the sort indicator
+    pub fn clear_sort_indicator(&mut self) {
+        self.touch_all();
+        self.sort_indicator = None;
+    }
+
+    /// Returns the sorted column and its direction, see [FancyTable::set_sort_indicator]
+    pub fn get_sort_indicator(&self) -> Option<(usize, SortDirection)> {
+        self.sort_indicator
+    }
+
+    /// Overrides the symbols of the sort indicator.
+    /// Passing [None] restores the default symbols of the charset of the render profile
+    pub fn set_sort_glyphs(&mut self, glyphs: Option<SortGlyphs>) {
+        self.touch_all();
+        self.sort_glyphs = glyphs;
+    }
+
+    /// Returns the cell the sort indicator is appended to and the symbol, if the sorted column exists
+    pub(crate) fn get_rendered_sort_indicator(&self) -> Option<(usize, usize, String)> {
+        let (col, direction) = self.sort_indicator?;
+        let row = self.header_rows.max(1) - 1;
+        if row >= self.get_row_count() || col >= self.get_column_count() {
+            return None;
+        }
+
+        let glyph = match &self.sort_glyphs {
+            Some(glyphs) => glyphs.get(direction).to_string(),
+            None => match self.get_render_profile().charset {
+                Charset::Unicode => SortGlyphs::unicode().get(direction).to_string(),
+                Charset::Ascii => SortGlyphs::ascii().get(direction).to_string(),
+            },
+        };
+        Some((row, col, glyph))
+    }
+
+    /// Adds a label spanning several columns above the first row, creating a two-level header.
+    /// Groups must not overlap, overlapping groups are ignored when rendering.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["Jan".into(), "Feb".into(), "Mar".into(), "Apr".into(), "May".into(), "Jun".into()],
+    /// ]);
+    /// table.add_column_group("Q1".into(), 0..3);
+    /// table.add_column_group("Q2".into(), 3..6);
+    /// ```
+    pub fn add_column_group(&mut self, label: String, columns: Range<usize>) {
+        self.touch_all();
+        self.column_groups.push(ColumnGroup { label, columns });
+    }
+
+    /// Removes all column groups
+    pub fn clear_column_groups(&mut self) {
+        self.touch_all();
+        self.column_groups.clear();
+    }
+
+    /// Returns the labels spanning several columns above the first row
+    pub fn get_column_groups(&self) -> &[ColumnGroup] {
+        &self.column_groups
+    }
+
+    /// Sets the expected type of data inside a column, checked by [FancyTable::validate]
+    pub fn set_column_type(&mut self, column: usize, column_type: ColumnType) {
+        self.column_types[column] = column_type;
+    }
+
+    /// Returns the expected type of data inside a column
+    pub fn get_column_type(&self, column: usize) -> ColumnType {
+        self.column_types[column]
+    }
+
+    /// Checks every cell below the header against the [ColumnType] of its column.
+    /// Empty cells are always valid.
+    ///
+    /// Returns all cells that do not match their column type
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::{ColumnType, FancyTable};
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["Name".into(), "Age".into()],
+    ///     vec!["Alice".into(), "42".into()],
+    ///     vec!["Bob".into(), "unknown".into()],
+    /// ]);
+    /// table.set_header_rows(1);
+    /// table.set_column_type(1, ColumnType::Integer);
+    ///
+    /// let errors = table.validate().unwrap_err();
+    /// assert_eq!((errors[0].row, errors[0].column), (2, 1));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        for (col, column_type) in self.column_types.iter().enumerate() {
+            if *column_type == ColumnType::Text {
+                continue;
+            }
+
+            for row in self.header_rows..self.get_row_count() {
+                let content = self.get(row, col).unwrap().get_content().join("\n");
+                if !column_type.accepts(&content) {
+                    errors.push(ValidationError { row, column: col, expected: *column_type, content });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            errors.sort_by_key(|error| (error.row, error.column));
+            Err(errors)
+        }
+    }
+
+    /// Sets the character filling the space between the content of a cell and its padding, e.g. `'.'` for leader dots.
+    /// Depending on the alignment of a cell, the space is filled on the left, the right or both sides of the content.
+    /// Empty cells and the padding itself always consist of spaces.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Chapter 1".into()], vec!["Appendix".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_padding_char('.');
+    /// assert_eq!(table.render_row(1), "| Appendix. |");
+    /// ```
+    pub fn set_padding_char(&mut self, padding_char: char) {
+        self.touch_all();
+        self.padding_char = padding_char;
+    }
+
+    /// Sets how escape sequences and control characters inside the content of cells are rendered.
+    /// Use [EscapeMode::Strip] or [EscapeMode::Visualize] for untrusted content,
+    /// so that it can neither move the cursor nor draw fake table structure
+    ///
+    /// The content of cells, the labels of column groups and the output of [FancyTable::to_plain_list] and the exporters are affected,
+    /// cell styles are still applied.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::{EscapeMode, FancyTable};
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["evil\x1b[2Aname".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::FANCY));
+    ///
+    /// table.set_escape_mode(EscapeMode::Strip);
+    /// assert_eq!(table.render_row(0), "│ evilname │");
+    ///
+    /// table.set_escape_mode(EscapeMode::Visualize);
+    /// assert_eq!(table.render_row(0), "│ evil␛[2Aname │");
+    ///
+    /// // the plain list used on narrow terminals is escaped as well
+    /// table.set_plain_list_threshold(Some(40));
+    /// table.set_max_width(Some(10));
+    /// assert_eq!(table.to_string(), "Column 1: evil␛[2Aname");
+    /// ```
+    pub fn set_escape_mode(&mut self, escape_mode: EscapeMode) {
+        self.touch_all();
+        self.escape_mode = escape_mode;
+    }
+
+    /// Returns how escape sequences and control characters inside the content of cells are rendered
+    pub fn get_escape_mode(&self) -> EscapeMode {
+        self.escape_mode
+    }
+
+    /// Sets the outer margin of the table: every rendered line is indented by `left` spaces and `top` blank lines are added above the table.
+    /// Useful to embed the table into nested output, e.g. below a list item
+    ///
+    /// [FancyTable::render_row] only applies the left margin.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Hello".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// table.set_margin(4, 1);
+    /// assert_eq!(table.to_string(), "\n    +-------+\n    | Hello |\n    +-------+");
+    /// ```
+    pub fn set_margin(&mut self, left: usize, top: usize) {
+        self.touch_all();
+        self.margin_left = left;
+        self.margin_top = top;
+    }
+
+    /// Returns the outer margin of the table in order: left, top
+    pub fn get_margin(&self) -> (usize, usize) {
+        (self.margin_left, self.margin_top)
+    }
+
+    /// Returns the profile used when rendering the table.
+    /// If no profile has been set, it is detected using [RenderProfile::detect]
+    pub fn get_render_profile(&self) -> RenderProfile {
+        self.render_profile.unwrap_or_else(RenderProfile::detect)
+    }
+
+    /// Overrides the profile used when rendering the table.
+    /// Passing [None] restores the automatic detection, so that piped output is plain ASCII without colors
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Hello".into(), "World".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// assert_eq!(table.to_string(), "+-------+-------+\n| Hello | World |\n+-------+-------+");
+    /// ```
+    pub fn set_render_profile(&mut self, profile: Option<RenderProfile>) {
+        self.touch_all();
+        self.render_profile = profile;
+    }
+
+    /// Highlights a row, column or cell when rendering, e.g. the cursor of a TUI.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::Selection;
+    /// let mut table = FancyTable::new(vec![vec!["a".into()], vec!["b".into()]]);
+    /// table.set_selection(Selection::Row(1));
+    /// ```
+    pub fn set_selection(&mut self, selection: Selection) {
+        self.touch_all();
+        self.selection = Some(selection);
+    }
+
+    /// Removes the highlighting of the selection
+    pub fn clear_selection(&mut self) {
+        self.touch_all();
+        self.selection = None;
+    }
+
+    /// Returns the currently highlighted part of the table
+    pub fn get_selection(&self) -> Option<Selection> {
+        self.selection
+    }
+
+    /// Sets the style of selected cells. Defaults to reversed colors
+    #[cfg(feature = "ansi")]
+    pub fn set_selection_style(&mut self, style: Style) {
+        self.touch_all();
+        self.selection_style = style;
+    }
+
+    /// If enabled, selected cells keep their own style with inverted colors instead of using the selection style
+    pub fn set_selection_inverted(&mut self, inverted: bool) {
+        self.touch_all();
+        self.selection_inverted = inverted;
+    }
+
+    /// Sets the style every cell inherits.
+    /// See [FancyTable::get_rendered_style] for how styles are combined
+    #[cfg(feature = "ansi")]
+    pub fn set_table_style(&mut self, style: Style) {
+        self.touch_all();
+        self.table_style = style;
+    }
+
+    /// Sets the style every cell of a column inherits.
+    /// See [FancyTable::get_rendered_style] for how styles are combined
+    #[cfg(feature = "ansi")]
+    pub fn set_column_style(&mut self, column: usize, style: Style) {
+        self.touch_all();
+        self.column_styles[column] = style;
+    }
+
+    /// Sets the style every cell of a row inherits.
+    /// See [FancyTable::get_rendered_style] for how styles are combined
+    #[cfg(feature = "ansi")]
+    pub fn set_row_style(&mut self, row: usize, style: Style) {
+        self.touch_row(row);
+        self.row_styles[row] = style;
+    }
+
+    /// Returns the style a cell is rendered with.
+    ///
+    /// The style of the table, the column, the row and the cell are combined in this order,
+    /// so that a more specific style beats a less specific one:
+    /// colors set by a later style replace earlier colors and attributes like bold are added up.
+    /// The header style of the called out column (see [FancyTable::emphasize_column_with]) follows the style of the cell.
+    /// Finally, the selection is applied on top.
+    ///
+    /// # Example
+    /// ```
+    /// use ansi_term::{Colour, Style};
+    /// use fancytable::FancyTable;
+    /// let mut table = FancyTable::new(vec![vec!["a".into(), "b".into()]]);
+    /// table.set_table_style(Style::new().fg(Colour::Red));
+    /// table.set_column_style(1, Style::new().bold());
+    /// table.get_mut(0, 1).unwrap().style = Style::new().fg(Colour::Blue);
+    /// assert_eq!(table.get_rendered_style(0, 1), Style::new().fg(Colour::Blue).bold());
+    /// ```
+    #[cfg(feature = "ansi")]
+    pub fn get_rendered_style(&self, row_idx: usize, col_idx: usize) -> Style {
+        let style = [
+            self.column_styles.get(col_idx),
+            self.row_styles.get(row_idx),
+            self.get(row_idx, col_idx).map(|cell| &cell.style),
+            self.get_emphasized_header_style(row_idx, col_idx),
+        ].into_iter()
+            .flatten()
+            .fold(self.table_style, |style, over| compose_styles(style, *over));
+        match self.selection {
+            Some(selection) if selection.contains(row_idx, col_idx) => {
+                if self.selection_inverted {
+                    style.reverse()
+                } else {
+                    self.selection_style
+                }
+            }
+            _ => style,
+        }
+    }
+
+    /// Returns the header style of the called out column if the cell is in one of its header rows,
+    /// or in the first row if there are no header rows
+    #[cfg(feature = "ansi")]
+    fn get_emphasized_header_style(&self, row_idx: usize, col_idx: usize) -> Option<&Style> {
+        match &self.column_emphasis {
+            Some((col, emphasis)) if *col == col_idx && row_idx < self.header_rows.max(1) => emphasis.header_style.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl FancyTable {
+    /// Writes the top border of a single row to the formatter.
+    /// Rows outside of `rows` are treated as missing, so that the border closes the frame around them
+    fn write_top_border(&self, f: &mut dyn Write, row_idx: usize, rows: &Range<usize>, widths: &[usize], profile: &RenderProfile) -> std::fmt::Result {
+        let border_style = |row: i64, col: i64| {
+            if row < 0 || !rows.contains(&(row as usize)) {
+                return None;
+            }
+            let mut style = self.get_rendered_border_style(row, col)?;
+            // the border around a window of rows stays closed along hidden separators and collapsed cells
+            let own = self.get_cell(row, col)?.border_style;
+            if row as usize == rows.start && rows.start > 0 {
+                style.top = own.top;
+            }
+            if row as usize + 1 == rows.end && rows.end < self.get_row_count() {
+                style.bottom = own.bottom;
+            }
+            Some(style)
+        };
+
+        let default_style = BorderStyle::default();
+        let hor_style = self.get_horizontal_separator_style(row_idx).unwrap_or(&default_style);
+        // the line between a cell and the cell above it
+        let line_symbol = |col: i64| {
+            let line = border_style(row_idx as i64, col).unwrap_or_default().top
+                .max(border_style(row_idx as i64 - 1, col).unwrap_or_default().bottom);
+            convert_symbol(get_horizontal_symbol(&line, hor_style), profile.charset)
+        };
+
         for col_idx in 0..(self.get_column_count() + 1) {
-            let cell = self.get(row_idx, col_idx);
-            let top_left = self.get_cell(row_idx as i64 - 1, col_idx as i64 - 1);
-            let top_right = self.get_cell(row_idx as i64 - 1, col_idx as i64);
-            let left = self.get_cell(row_idx as i64, col_idx as i64 - 1);
+            let (row, col) = (row_idx as i64, col_idx as i64);
+            let cell = border_style(row, col);
+            let top_left = border_style(row - 1, col - 1);
+            let top_right = border_style(row - 1, col);
+            let left = border_style(row, col - 1);
+            let (mut top, left, right, bottom) = get_common_cell_border_arms(top_left, top_right, left, cell);
+            // the column groups above the first row only have lines at their boundaries
+            if row_idx == 0 && !self.column_groups.is_empty() {
+                top = self.get_group_boundaries().contains(&col_idx);
+            }
 
-            let default_style = BorderStyle::default();
-            let hor_style = self.get_horizontal_separator_style(row_idx).unwrap_or(&default_style);
-            let vert_style = self.get_vertical_separator_style(col_idx).unwrap_or(&default_style);
-            // cell corner symbol
-            write!(f, "{}", get_common_cell_border_symbol(top_left, top_right, left, cell, hor_style.clone(), vert_style.clone()))?;
+            let vert_style = self.get_rendered_vertical_separator_style(col_idx).unwrap_or(&default_style);
+            // cell corner symbol, a gap continues the horizontal line through it
+            match self.get_column_separator_gap(col_idx) {
+                Some(gap) if left && right => write!(f, "{}", line_symbol(col - 1).repeat(gap))?,
+                Some(gap) => write!(f, "{:gap$}", "")?,
+                None => {
+                    let symbol = get_center_symbol(top, left, right, bottom, *hor_style, *vert_style);
+                    write!(f, "{}", convert_symbol(symbol, profile.charset))?;
+                }
+            }
 
             // top border
-            if col_idx == self.get_column_count() {
-                continue;
+            if let Some(width) = widths.get(col_idx) {
+                write!(f, "{}", line_symbol(col).repeat(*width))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the columns at which a column group starts, including the right border of the table
+    fn get_group_boundaries(&self) -> Vec<usize> {
+        group_segments(&self.column_groups, self.get_column_count()).iter()
+            .map(|(columns, _)| columns.start)
+            .chain(std::iter::once(self.get_column_count()))
+            .collect()
+    }
+
+    /// Returns the number of lines of the column group labels, 0 if there are no groups
+    fn get_group_height(&self) -> usize {
+        if self.column_groups.is_empty() {
+            return 0;
+        }
+        self.column_groups.iter()
+            .map(|group| group.label.lines().count())
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// Writes the column group labels and their top border, followed by a line break
+    fn write_column_groups(&self, f: &mut dyn Write, widths: &[usize], profile: &RenderProfile) -> std::fmt::Result {
+        let boundaries = self.get_group_boundaries();
+        let default_style = BorderStyle::default();
+        let hor_style = *self.get_horizontal_separator_style(0).unwrap_or(&default_style);
+        let vert_style = |col: usize| *self.get_rendered_vertical_separator_style(col).unwrap_or(&default_style);
+
+        // top border
+        for col_idx in 0..(widths.len() + 1) {
+            let (left, right) = (col_idx > 0, col_idx < widths.len());
+            match self.get_column_separator_gap(col_idx) {
+                Some(gap) if left && right => {
+                    let symbol = convert_symbol(get_horizontal_symbol(&BorderLineStyle::Solid, &hor_style), profile.charset);
+                    write!(f, "{}", symbol.repeat(gap))?;
+                }
+                Some(gap) => write!(f, "{:gap$}", "")?,
+                None => {
+                    let symbol = get_center_symbol(false, left, right, boundaries.contains(&col_idx), hor_style, vert_style(col_idx));
+                    write!(f, "{}", convert_symbol(symbol, profile.charset))?;
+                }
+            }
+
+            if let Some(width) = widths.get(col_idx) {
+                let symbol = convert_symbol(get_horizontal_symbol(&BorderLineStyle::Solid, &hor_style), profile.charset);
+                write!(f, "{}", symbol.repeat(*width))?;
             }
-            for _ in 0..widths[col_idx] {
-                write!(f, "{}", get_cell_border_symbols(self, row_idx, col_idx).0)?;
+        }
+        writeln!(f)?;
+
+        // labels
+        for line in 0..self.get_group_height() {
+            for (columns, label) in group_segments(&self.column_groups, widths.len()) {
+                let symbol = get_vertical_symbol(&BorderLineStyle::Solid, &vert_style(columns.start));
+                write!(f, "{}", self.render_vertical_separator(columns.start, symbol, profile))?;
+
+                let separators = (columns.start + 1..columns.end).map(|idx| self.get_separator_width(idx)).sum::<usize>();
+                let width = widths[columns.clone()].iter().sum::<usize>() + separators;
+                let content = self.escape_mode.apply(label.lines().nth(line).unwrap_or_default());
+                let content = if profile.colored { content } else { strip_ansi_escapes::strip_str(content) };
+                write!(f, " {} ", align_line(&content, width - 2, Alignment::Center, ' '))?;
             }
+            let symbol = get_vertical_symbol(&BorderLineStyle::Solid, &vert_style(widths.len()));
+            writeln!(f, "{}", self.render_vertical_separator(widths.len(), symbol, profile))?;
         }
+
         Ok(())
     }
 
-    /// Writes a single row to the formatter
-    fn write_row(&self, f: &mut Formatter<'_>, row_idx: usize, widths: &Vec<usize>) -> std::fmt::Result {
-        let height: i64 = self.get_row_height(row_idx) as i64;
-        if height > 0 {
-            for line in 0..height {
-                for col_idx in 0..self.get_column_count() {
+    /// Writes a single row to the formatter with a number of blank filler lines,
+    /// which are written below the content or above it for the last row
+    fn write_row(&self, f: &mut dyn Write, row_idx: usize, layout: &Layout, profile: &RenderProfile, filler_lines: usize) -> std::fmt::Result {
+        let height: i64 = layout.row_height(row_idx) as i64;
+        let total_height = height + filler_lines as i64;
+        // the filler lines follow the content, except for the footer which stays at the bottom
+        let filler_above = if row_idx + 1 == self.get_row_count() { filler_lines as i64 } else { 0 };
+        if total_height > 0 {
+            for output_line in 0..total_height {
+                let line = output_line - filler_above;
+                for (col_idx, width) in layout.column_widths.iter().enumerate() {
                     let cell = self.get(row_idx, col_idx).unwrap();
                     let symbols = get_cell_border_symbols(self, row_idx, col_idx);
                     if col_idx == 0 {
-                        write!(f, "{}", symbols.1)?;
+                        write!(f, "{}", self.render_vertical_separator(0, symbols.1, profile))?;
                     }
 
                     // vertical alignment
+                    let lines = layout.lines(row_idx, col_idx);
                     let current_line: i64 = match cell.vertical_alignment {
                         VerticalAlignment::Top => line,
                         VerticalAlignment::Center => {
-                            line - (height - cell.get_height(self.column_widths[col_idx]) as i64) / 2
+                            line - (height - lines.len() as i64) / 2
                         }
                         VerticalAlignment::Bottom => {
-                            line - height + cell.get_height(self.column_widths[col_idx]) as i64
+                            line - height + lines.len() as i64
                         }
                     };
 
-                    let content = match current_line {
-                        neg if neg < 0 => String::new(),
-                        line => cell.get_line(line as usize, self.column_widths[col_idx]).unwrap_or(String::new()),
+                    let mut content = match current_line {
+                        neg if neg < 0 || line >= height => String::new(),
+                        line => lines.get(line as usize).cloned().unwrap_or_default(),
                     };
+                    if !profile.colored {
+                        content = strip_ansi_escapes::strip_str(content);
+                    }
 
-                    let aligned = match cell.horizontal_alignment {
-                        Alignment::Left => format!("{content:<width$}", width = widths[col_idx]),
-                        Alignment::Right => format!("{content:>width$}", width = widths[col_idx]),
-                        Alignment::Center => format!("{content:^width$}", width = widths[col_idx]),
-                    };
-                    let styled = cell.style.paint(&aligned);
-                    write!(f, "{styled}")?;
-                    write!(f, "{}", symbols.2)?;
+                    // the padding always consists of spaces, the fill character is only used next to the content
+                    let padding = cell.get_padding(self.column_widths[col_idx]);
+                    let fill = if content.trim().is_empty() { ' ' } else { self.padding_char };
+                    let aligned = format!(
+                        "{empty:padding$}{}{empty:padding$}",
+                        align_line(&content, width.saturating_sub(2 * padding), cell.horizontal_alignment, fill),
+                        empty = "",
+                    );
+                    if profile.colored {
+                        write!(f, "{}", self.paint(row_idx, col_idx, &aligned))?;
+                    } else {
+                        write!(f, "{aligned}")?;
+                    }
+                    write!(f, "{}", self.render_vertical_separator(col_idx + 1, symbols.2, profile))?;
                 }
-                if line != height - 1 {
+                if output_line != total_height - 1 {
                     writeln!(f)?;
                 }
             }
@@ -310,31 +1593,267 @@ impl FancyTable {
         }
         Ok(())
     }
-}
 
-impl Display for FancyTable {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    /// Applies the rendered style of a cell to an already aligned line
+    #[cfg(feature = "ansi")]
+    fn paint(&self, row_idx: usize, col_idx: usize, line: &str) -> String {
+        self.get_rendered_style(row_idx, col_idx).paint(line).to_string()
+    }
+
+    /// Without the `ansi` feature, cells have no style and the line is returned as is
+    #[cfg(not(feature = "ansi"))]
+    fn paint(&self, _row_idx: usize, _col_idx: usize, line: &str) -> String {
+        line.to_string()
+    }
+
+    /// Writes the whole table.
+    /// If `height` is set, blank lines are added above the last row until the output has exactly this many lines
+    pub(crate) fn write_table(&self, f: &mut dyn Write, height: Option<usize>, profile: &RenderProfile) -> std::fmt::Result {
         // capture empty tables
         if self.get_column_count() < 1 || self.get_row_count() < 1 {
             return Ok(());
         }
 
-        let widths = self.get_col_widths();
-        for row_idx in 0..(self.get_row_count() + 1) {
-            self.write_top_border(f, row_idx, &widths)?;
+        if let (Some(max_width), Some(threshold)) = (self.max_width, self.plain_list_threshold) {
+            if max_width < threshold {
+                let f: &mut dyn Write = &mut Indented::new(f, self.margin_left);
+                return write!(f, "{}{}", "\n".repeat(self.margin_top), self.to_plain_list());
+            }
+        }
+
+        if let Some((view, hidden)) = self.fit_to_max_width() {
+            let note = match hidden {
+                0 => return view.write_table(f, height, profile),
+                1 => "(1 column hidden)".to_string(),
+                n => format!("({n} columns hidden)"),
+            };
+            view.write_table(f, height.map(|height| height.saturating_sub(1)), profile)?;
+            return write!(f, "\n{:indent$}{note}", "", indent = self.margin_left);
+        }
+
+        let layout = Layout::compute(self);
+        let f: &mut dyn Write = &mut Indented::new(f, self.margin_left);
+
+        for _ in 0..self.margin_top {
+            writeln!(f)?;
+        }
+
+        // every row is followed by a border, plus the top border of the table
+        let hidden_separators = (1..self.get_row_count()).filter(|&idx| !self.is_row_separator_visible(idx)).count();
+        let natural_height = layout.total_row_height() + self.get_row_count() + 1 - hidden_separators + self.get_group_height()
+            + if self.column_groups.is_empty() { 0 } else { 1 } + self.margin_top;
+        let filler_lines = height.unwrap_or(0).saturating_sub(natural_height);
+        // the blank lines are added below the row above the footer, or above the footer if it is the only row
+        let filler_row = self.get_row_count().saturating_sub(2);
+
+        if !self.column_groups.is_empty() {
+            self.write_column_groups(f, &layout.column_widths, profile)?;
+        }
 
-            if row_idx == self.get_row_count() {
+        self.write_rows(f, &layout, profile, filler_lines, filler_row)
+    }
+
+    /// Writes the rows of the layout with the borders above and below every row.
+    /// `filler_lines` blank lines are added to the row `filler_row`
+    fn write_rows(&self, f: &mut dyn Write, layout: &Layout, profile: &RenderProfile, filler_lines: usize, filler_row: usize) -> std::fmt::Result {
+        let rows = &layout.rows;
+        for row_idx in rows.start..(rows.end + 1) {
+            // hidden separators between rows are skipped entirely
+            let interior = row_idx > rows.start && row_idx < rows.end;
+            if interior && !self.is_row_separator_visible(row_idx) {
+                let filler = if row_idx == filler_row { filler_lines } else { 0 };
+                self.write_row(f, row_idx, layout, profile, filler)?;
+                continue;
+            }
+
+            self.write_top_border(f, row_idx, rows, &layout.column_widths, profile)?;
+
+            if row_idx == rows.end {
                 continue;
             }
 
             writeln!(f)?;
-            self.write_row(f, row_idx, &widths)?;
+            let filler = if row_idx == filler_row { filler_lines } else { 0 };
+            self.write_row(f, row_idx, layout, profile, filler)?;
         }
 
         Ok(())
     }
+
+    /// Renders the lines of a single row including the vertical borders, but without the horizontal borders above and below.
+    /// The column widths of the whole table are used, so that the row lines up with a previously rendered table.
+    ///
+    /// Together with [FancyTable::get_changed_rows], this allows redrawing only the rows that changed.
+    /// If the widths of the columns change, the whole table has to be redrawn.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Hello".into(), "World".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// assert_eq!(table.render_row(0), "| Hello | World |");
+    /// ```
+    pub fn render_row(&self, row_idx: usize) -> String {
+        let mut rendered = String::new();
+        let layout = Layout::compute_rows(self, row_idx..(row_idx + 1));
+        // writing to a String never fails
+        let _ = self.write_row(&mut Indented::new(&mut rendered, self.margin_left), row_idx, &layout, &self.get_render_profile(), 0);
+        rendered.truncate(rendered.trim_end_matches('\n').len());
+        rendered
+    }
+
+    /// Renders `count` rows starting at `start_row` with a closed border around them, e.g. the visible part of a scrollable view.
+    /// The column widths of the whole table are used, so that the columns do not move while scrolling,
+    /// but only the lines of the rows inside the window are computed.
+    ///
+    /// The column groups are only rendered if the window starts at the first row.
+    /// Like [FancyTable::render_row], only the left margin is applied and [FancyTable::set_max_width] is ignored.
+    /// Rows past the end of the table are left out
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![
+    ///     vec!["1".into(), "one".into()],
+    ///     vec!["2".into(), "two".into()],
+    ///     vec!["3".into(), "three".into()],
+    /// ]);
+    /// table.set_render_profile(Some(RenderProfile::FANCY));
+    /// assert_eq!(table.render_window(1, 1), "┌───┬───────┐\n│ 2 │ two   │\n└───┴───────┘");
+    /// ```
+    pub fn render_window(&self, start_row: usize, count: usize) -> String {
+        let rows = start_row.min(self.get_row_count())..start_row.saturating_add(count).min(self.get_row_count());
+        let mut rendered = String::new();
+        if rows.is_empty() || self.get_column_count() < 1 {
+            return rendered;
+        }
+
+        let profile = self.get_render_profile();
+        let layout = Layout::compute_rows(self, rows.clone());
+        let f: &mut dyn Write = &mut Indented::new(&mut rendered, self.margin_left);
+        // writing to a String never fails
+        if rows.start == 0 && !self.column_groups.is_empty() {
+            let _ = self.write_column_groups(f, &layout.column_widths, &profile);
+        }
+        let _ = self.write_rows(f, &layout, &profile, 0, rows.end);
+        rendered
+    }
+
+    /// Renders the table so that it fills exactly `height` lines, e.g. a fixed size pane of a TUI.
+    ///
+    /// The last row is treated as the footer and stays at the bottom,
+    /// blank lines are inserted above it while the vertical borders continue through them.
+    /// If the table is already taller than `height`, it is rendered as usual.
+    /// The top margin set by [FancyTable::set_margin] counts towards `height`.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let table = FancyTable::new(vec![
+    ///     vec!["Item".into(), "Price".into()],
+    ///     vec!["Total".into(), "42".into()],
+    /// ]);
+    /// let rendered = table.render_filled(10);
+    /// assert_eq!(rendered.lines().count(), 10);
+    ///
+    /// // a single row is the footer itself
+    /// let mut table = FancyTable::new(vec![vec!["Total".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// assert_eq!(table.render_filled(5), "+-------+\n|       |\n|       |\n| Total |\n+-------+");
+    /// ```
+    pub fn render_filled(&self, height: usize) -> String {
+        let mut rendered = String::new();
+        // writing to a String never fails
+        let _ = self.write_table(&mut rendered, Some(height), &self.get_render_profile());
+        rendered
+    }
+
+    /// Renders the table centered within `total_width` columns, e.g. the width of the terminal.
+    /// Every line is shifted by the same amount, so the table stays intact.
+    /// If the table is wider than `total_width`, it is rendered as usual.
+    ///
+    /// # Example
+    /// ```
+    /// use fancytable::FancyTable;
+    /// use fancytable::style::profile::RenderProfile;
+    /// let mut table = FancyTable::new(vec![vec!["Done".into()]]);
+    /// table.set_render_profile(Some(RenderProfile::PLAIN));
+    /// assert_eq!(table.render_centered(16), "    +------+\n    | Done |\n    +------+");
+    /// ```
+    pub fn render_centered(&self, total_width: usize) -> String {
+        self.render_aligned(total_width, Alignment::Center)
+    }
+
+    /// Renders the table right-aligned within `total_width` columns, e.g. the width of the terminal.
+    /// See [FancyTable::render_centered]
+    pub fn render_right(&self, total_width: usize) -> String {
+        self.render_aligned(total_width, Alignment::Right)
+    }
+
+    /// Renders the table and shifts every line according to the alignment of the whole table within `total_width`
+    fn render_aligned(&self, total_width: usize, alignment: Alignment) -> String {
+        let rendered = self.to_string();
+        let width = rendered.lines().map(display_width).max().unwrap_or(0);
+        let space = total_width.saturating_sub(width);
+        let indent = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => space / 2,
+            Alignment::Right => space,
+        };
+
+        let mut aligned = String::new();
+        // writing to a String never fails
+        let _ = write!(Indented::new(&mut aligned, indent), "{rendered}");
+        aligned
+    }
+}
+
+/// Indents every non-empty line written to the inner writer
+struct Indented<'a> {
+    inner: &'a mut dyn Write,
+    indent: usize,
+    line_start: bool,
+}
+
+impl<'a> Indented<'a> {
+    fn new(inner: &'a mut dyn Write, indent: usize) -> Indented<'a> {
+        Indented { inner, indent, line_start: true }
+    }
 }
 
+impl Write for Indented<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.inner.write_char('\n')?;
+                self.line_start = true;
+            }
+            if line.is_empty() {
+                continue;
+            }
+
+            if self.line_start {
+                write!(self.inner, "{:1$}", "", self.indent)?;
+                self.line_start = false;
+            }
+            self.inner.write_str(line)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for FancyTable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_table(f, None, &self.get_render_profile())
+    }
+}
+
+impl Eq for FancyTable {}
+
 impl Default for FancyTable {
     fn default() -> Self {
         FancyTable::new(vec![vec!["".into()]])
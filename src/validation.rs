@@ -0,0 +1,103 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The type of data expected inside a column, checked by [FancyTable::validate](crate::FancyTable::validate)
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ColumnType {
+    /// Any content
+    #[default]
+    Text,
+    /// A whole number, e.g. `-42`
+    Integer,
+    /// A floating point number, e.g. `3.14` or `1e-3`
+    Float,
+    /// A date in the format `YYYY-MM-DD`
+    Date,
+}
+
+impl ColumnType {
+    /// Returns whether the content is valid for this type.
+    /// Surrounding whitespace is ignored and empty content is always valid
+    pub fn accepts(&self, content: &str) -> bool {
+        let content = content.trim();
+        if content.is_empty() {
+            return true;
+        }
+
+        match self {
+            ColumnType::Text => true,
+            ColumnType::Integer => content.parse::<i64>().is_ok(),
+            ColumnType::Float => content.parse::<f64>().is_ok(),
+            ColumnType::Date => is_date(content),
+        }
+    }
+}
+
+impl Display for ColumnType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Text => write!(f, "text"),
+            ColumnType::Integer => write!(f, "integer"),
+            ColumnType::Float => write!(f, "float"),
+            ColumnType::Date => write!(f, "date"),
+        }
+    }
+}
+
+/// Checks for a valid date in the format `YYYY-MM-DD`
+fn is_date(content: &str) -> bool {
+    let parts: Vec<&str> = content.split('-').collect();
+    let [year, month, day] = parts[..] else {
+        return false;
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return false;
+    }
+
+    let (Ok(year), Ok(month), Ok(day)) = (year.parse::<u32>(), month.parse::<u32>(), day.parse::<u32>()) else {
+        return false;
+    };
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap_year => 29,
+        2 => 28,
+        _ => return false,
+    };
+
+    (1..=days).contains(&day)
+}
+
+/// A cell whose content does not match the [ColumnType] of its column
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ValidationError {
+    pub row: usize,
+    pub column: usize,
+    pub expected: ColumnType,
+    /// The content of the cell, multiple lines are joined by `\n`
+    pub content: String,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cell ({}, {}): expected {}, found {:?}", self.row, self.column, self.expected, self.content)
+    }
+}
+
+impl Error for ValidationError {}
+
+/// A table with a different number of columns than expected, returned by [FancyTable::extend_from](crate::FancyTable::extend_from)
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct ColumnCountError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for ColumnCountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected {} columns, found {}", self.expected, self.found)
+    }
+}
+
+impl Error for ColumnCountError {}